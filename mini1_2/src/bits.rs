@@ -0,0 +1,220 @@
+use super::*;
+
+const BITS: u64 = DIGIT_BITS as u64;
+
+impl BigInteger {
+    fn to_twos_complement(&self, len: usize) -> Vec<Digit> {
+        let mut digits = self.digits.to_vec();
+        digits.resize(len, 0);
+        if self.signum() < 0 {
+            negate(&mut digits);
+        }
+        digits
+    }
+
+    fn from_twos_complement(mut digits: Vec<Digit>) -> Self {
+        let negative =
+            digits.last().is_some_and(|&d| d >> (DIGIT_BITS - 1) == 1);
+        if negative {
+            negate(&mut digits);
+        }
+        Self::from_parts(digits, if negative { -1 } else { 1 })
+    }
+
+    fn bitwise(&self, rhs: &Self, op: impl Fn(Digit, Digit) -> Digit) -> Self {
+        let len = max(self.len(), rhs.len()) + 1;
+        let lhs = self.to_twos_complement(len);
+        let rhs = rhs.to_twos_complement(len);
+        Self::from_twos_complement(
+            lhs.into_iter().zip(rhs).map(|(x, y)| op(x, y)).collect(),
+        )
+    }
+
+    /// Number of bits in the magnitude, `0` for zero.
+    pub fn bit_length(&self) -> u64 {
+        match self.digits.last() {
+            Some(d) => self.len() as u64 * BITS - d.leading_zeros() as u64,
+            None => 0,
+        }
+    }
+
+    /// Number of ones in the magnitude.
+    pub fn count_ones(&self) -> u64 {
+        self.digits.iter().map(|d| d.count_ones() as u64).sum()
+    }
+
+    /// Number of trailing zeros, which is the same for a value and its
+    /// negation. Returns `None` for zero.
+    pub fn trailing_zeros(&self) -> Option<u64> {
+        let i = self.digits.iter().position(|&d| d != 0)?;
+        Some(i as u64 * BITS + self.digits[i].trailing_zeros() as u64)
+    }
+
+    fn magnitude_bit(&self, i: u64) -> bool {
+        self.digits
+            .get((i / BITS) as usize)
+            .is_some_and(|d| d >> (i % BITS) & 1 == 1)
+    }
+
+    /// Bit `i` of the infinite two's complement representation.
+    pub fn bit(&self, i: u64) -> bool {
+        match (self.signum(), self.trailing_zeros()) {
+            (-1, Some(tz)) => i == tz || i > tz && !self.magnitude_bit(i),
+            _ => self.magnitude_bit(i),
+        }
+    }
+
+    /// Sets bit `i` of the infinite two's complement representation.
+    pub fn set_bit(&mut self, i: u64, value: bool) {
+        let k = (i / BITS) as usize;
+        let len = max(self.len(), k + 1) + 1;
+        let mut digits = self.to_twos_complement(len);
+        match value {
+            true => digits[k] |= 1 << (i % BITS),
+            false => digits[k] &= !(1 << (i % BITS)),
+        }
+        *self = Self::from_twos_complement(digits);
+    }
+}
+
+impl BitAnd<&BigInteger> for &BigInteger {
+    type Output = BigInteger;
+
+    fn bitand(self, rhs: &BigInteger) -> Self::Output {
+        self.bitwise(rhs, |x, y| x & y)
+    }
+}
+
+impl BitOr<&BigInteger> for &BigInteger {
+    type Output = BigInteger;
+
+    fn bitor(self, rhs: &BigInteger) -> Self::Output {
+        self.bitwise(rhs, |x, y| x | y)
+    }
+}
+
+impl BitXor<&BigInteger> for &BigInteger {
+    type Output = BigInteger;
+
+    fn bitxor(self, rhs: &BigInteger) -> Self::Output {
+        self.bitwise(rhs, |x, y| x ^ y)
+    }
+}
+
+impl BitAndAssign<&BigInteger> for BigInteger {
+    fn bitand_assign(&mut self, rhs: &BigInteger) {
+        *self = &*self & rhs;
+    }
+}
+
+impl BitOrAssign<&BigInteger> for BigInteger {
+    fn bitor_assign(&mut self, rhs: &BigInteger) {
+        *self = &*self | rhs;
+    }
+}
+
+impl BitXorAssign<&BigInteger> for BigInteger {
+    fn bitxor_assign(&mut self, rhs: &BigInteger) {
+        *self = &*self ^ rhs;
+    }
+}
+
+forward_assign!(BitAndAssign, bitand_assign);
+forward_assign!(BitOrAssign, bitor_assign);
+forward_assign!(BitXorAssign, bitxor_assign);
+
+forward_binop_to_ref!(BitAnd, bitand);
+forward_binop_to_ref!(BitOr, bitor);
+forward_binop_to_ref!(BitXor, bitxor);
+
+impl Not for BigInteger {
+    type Output = BigInteger;
+
+    fn not(self) -> Self::Output {
+        -self - 1_u8
+    }
+}
+
+impl Not for &BigInteger {
+    type Output = BigInteger;
+
+    fn not(self) -> Self::Output {
+        !self.clone()
+    }
+}
+
+impl ShlAssign<usize> for BigInteger {
+    fn shl_assign(&mut self, n: usize) {
+        if self.bit_length() + n as u64 <= u128::BITS as u64 {
+            let x = self.to_u128().unwrap();
+            *self = Self::from_u128(
+                x.checked_shl(n as u32).unwrap_or(0),
+                self.signum,
+            );
+            return;
+        }
+        let digits = mem::take(&mut self.digits).into_vec();
+        let digits = shl_bits(digits, (n % DIGIT_BITS as usize) as u32);
+        self.digits = lsh(digits, n / DIGIT_BITS as usize).into();
+    }
+}
+
+impl ShrAssign<usize> for BigInteger {
+    /// Arithmetic shift, rounding toward negative infinity.
+    fn shr_assign(&mut self, n: usize) {
+        let k = n / DIGIT_BITS as usize;
+        let rounds_down = self.signum() < 0
+            && self.trailing_zeros().is_some_and(|tz| tz < n as u64);
+        *self = match self.to_u128() {
+            Some(x) => Self::from_u128(
+                x.checked_shr(n.try_into().unwrap_or(u32::MAX)).unwrap_or(0),
+                self.signum,
+            ),
+            None => {
+                let mut digits = mem::take(&mut self.digits).into_vec();
+                digits.drain(..min(k, digits.len()));
+                Self::from_parts(
+                    shr_bits(digits, (n % DIGIT_BITS as usize) as u32),
+                    self.signum,
+                )
+            }
+        };
+        if rounds_down {
+            *self -= 1_u8;
+        }
+    }
+}
+
+impl Shl<usize> for BigInteger {
+    type Output = BigInteger;
+
+    fn shl(mut self, n: usize) -> Self::Output {
+        self <<= n;
+        self
+    }
+}
+
+impl Shr<usize> for BigInteger {
+    type Output = BigInteger;
+
+    fn shr(mut self, n: usize) -> Self::Output {
+        self >>= n;
+        self
+    }
+}
+
+impl Shl<usize> for &BigInteger {
+    type Output = BigInteger;
+
+    fn shl(self, n: usize) -> Self::Output {
+        self.clone() << n
+    }
+}
+
+impl Shr<usize> for &BigInteger {
+    type Output = BigInteger;
+
+    fn shr(self, n: usize) -> Self::Output {
+        self.clone() >> n
+    }
+}
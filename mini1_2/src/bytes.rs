@@ -0,0 +1,133 @@
+use super::*;
+
+impl BigInteger {
+    /// Returns the sign and the big-endian bytes of the magnitude, `[0]` for
+    /// zero.
+    pub fn to_bytes_be(&self) -> (i8, Vec<u8>) {
+        let (signum, mut bytes) = self.to_bytes_le();
+        bytes.reverse();
+        (signum, bytes)
+    }
+
+    /// Returns the sign and the little-endian bytes of the magnitude, `[0]`
+    /// for zero.
+    pub fn to_bytes_le(&self) -> (i8, Vec<u8>) {
+        let bytes = self.digits.iter().flat_map(|d| d.to_le_bytes()).collect();
+        let mut bytes = trunc(bytes);
+        if bytes.is_empty() {
+            bytes.push(0);
+        }
+        (self.signum(), bytes)
+    }
+
+    /// Builds a value from big-endian magnitude bytes, negative if `signum`
+    /// is negative. A `signum` of zero gives zero.
+    pub fn from_bytes_be(signum: i8, bytes: &[u8]) -> Self {
+        let bytes: Vec<u8> = bytes.iter().rev().copied().collect();
+        Self::from_bytes_le(signum, &bytes)
+    }
+
+    /// Builds a value from little-endian magnitude bytes, negative if
+    /// `signum` is negative. A `signum` of zero gives zero.
+    pub fn from_bytes_le(signum: i8, bytes: &[u8]) -> Self {
+        if signum == 0 {
+            return Self::ZERO;
+        }
+        let digits = bytes
+            .chunks(DIGIT_BITS as usize / 8)
+            .map(|chunk| {
+                let mut d = [0; DIGIT_BITS as usize / 8];
+                d[..chunk.len()].copy_from_slice(chunk);
+                Digit::from_le_bytes(d)
+            })
+            .collect();
+        Self::from_parts(digits, signum.signum())
+    }
+
+    /// Minimal big-endian two's complement bytes.
+    pub fn to_signed_bytes_be(&self) -> Vec<u8> {
+        let mut bytes = self.to_signed_bytes_le();
+        bytes.reverse();
+        bytes
+    }
+
+    /// Minimal little-endian two's complement bytes.
+    pub fn to_signed_bytes_le(&self) -> Vec<u8> {
+        let (signum, mut bytes) = self.to_bytes_le();
+        if signum < 0 {
+            negate_bytes(&mut bytes);
+        }
+        let top = *bytes.last().unwrap() >= 0x80;
+        match signum {
+            -1 if !top => bytes.push(0xff),
+            1 if top => bytes.push(0),
+            _ => (),
+        }
+        bytes
+    }
+
+    /// Reads big-endian two's complement bytes, zero if `bytes` is empty.
+    pub fn from_signed_bytes_be(bytes: &[u8]) -> Self {
+        let bytes: Vec<u8> = bytes.iter().rev().copied().collect();
+        Self::from_signed_bytes_le(&bytes)
+    }
+
+    /// Reads little-endian two's complement bytes, zero if `bytes` is empty.
+    pub fn from_signed_bytes_le(bytes: &[u8]) -> Self {
+        if bytes.last().is_some_and(|&b| b >= 0x80) {
+            let mut bytes = bytes.to_vec();
+            negate_bytes(&mut bytes);
+            Self::from_bytes_le(-1, &bytes)
+        } else {
+            Self::from_bytes_le(1, bytes)
+        }
+    }
+
+    /// Encodes the value as a zigzag LEB128 varint: `n >= 0` maps to `2n`
+    /// and `n < 0` to `-2n - 1`, which is then written seven bits per byte,
+    /// least significant first, with the high bit set on all but the last.
+    pub fn to_varint(&self) -> Vec<u8> {
+        let mut zigzag = self.clone().abs() << 1;
+        if self.signum() < 0 {
+            zigzag -= 1_u8;
+        }
+        let (_, bytes) = zigzag.to_bytes_le();
+        let bits = zigzag.bit_length().max(1);
+        (0..bits.div_ceil(7))
+            .map(|i| {
+                let (k, s) = ((i * 7 / 8) as usize, i * 7 % 8);
+                let word = bytes[k] as u16
+                    | (bytes.get(k + 1).copied().unwrap_or(0) as u16) << 8;
+                let more = (i + 1) * 7 < bits;
+                (word >> s) as u8 & 0x7f | (more as u8) << 7
+            })
+            .collect()
+    }
+
+    /// Decodes a varint written by [`to_varint`](Self::to_varint) from the
+    /// start of `bytes`, returning the value and the number of bytes read, or
+    /// `None` if the input ends before the last byte of the varint.
+    pub fn from_varint(bytes: &[u8]) -> Option<(Self, usize)> {
+        let len = bytes.iter().position(|b| b & 0x80 == 0)? + 1;
+        let mut out = vec![0_u8; (len * 7).div_ceil(8) + 1];
+        for (i, b) in bytes[..len].iter().enumerate() {
+            let (k, s) = (i * 7 / 8, i * 7 % 8);
+            let word = ((b & 0x7f) as u16) << s;
+            out[k] |= word as u8;
+            out[k + 1] |= (word >> 8) as u8;
+        }
+        let zigzag = Self::from_bytes_le(1, &out);
+        let value = match zigzag.bit(0) {
+            true => -((zigzag + 1_u8) >> 1),
+            false => zigzag >> 1,
+        };
+        Some((value, len))
+    }
+}
+
+fn negate_bytes(x: &mut [u8]) {
+    let mut c = true;
+    for b in x.iter_mut() {
+        (*b, c) = (!*b).overflowing_add(c as u8);
+    }
+}
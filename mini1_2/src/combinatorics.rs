@@ -0,0 +1,96 @@
+use super::prime::small_primes;
+use super::*;
+
+impl BigInteger {
+    /// `n!`.
+    pub fn factorial(n: u64) -> Self {
+        product_of_small(2..=n)
+    }
+
+    /// `n!!`, the product of the positive integers up to `n` with the same
+    /// parity as `n`.
+    pub fn double_factorial(n: u64) -> Self {
+        product_of_small((2 - n % 2..=n).rev().step_by(2))
+    }
+
+    /// The number of ways to choose `k` items from `n`, zero if `k > n`.
+    pub fn binomial(n: u64, k: u64) -> Self {
+        if k > n {
+            return Self::ZERO;
+        }
+        let k = min(k, n - k);
+        product_of_small(n - k + 1..=n) / Self::factorial(k)
+    }
+
+    /// The `n`th Fibonacci number, with `F(0) = 0` and `F(1) = 1`.
+    pub fn fibonacci(n: u64) -> Self {
+        fibonacci_pair(n).0
+    }
+
+    /// The `n`th Lucas number, with `L(0) = 2` and `L(1) = 1`.
+    pub fn lucas(n: u64) -> Self {
+        let (f, g) = fibonacci_pair(n);
+        (g << 1) - f
+    }
+
+    /// The product of the primes up to `n`.
+    pub fn primorial(n: u64) -> Self {
+        product_of_small(small_primes(n))
+    }
+}
+
+/// Returns `(F(n), F(n + 1))` by fast doubling.
+fn fibonacci_pair(n: u64) -> (BigInteger, BigInteger) {
+    let (mut f, mut g) = (BigInteger::ZERO, BigInteger::from(1_u8));
+    for i in (0..u64::BITS - n.leading_zeros()).rev() {
+        let f2 = &f * &((&g << 1) - &f);
+        let g2 = &f * &f + &g * &g;
+        (f, g) = match n >> i & 1 {
+            1 => (g2.clone(), f2 + g2),
+            _ => (f2, g2),
+        };
+    }
+    (f, g)
+}
+
+/// Multiplies small factors, packing them into limbs first and then
+/// combining the limbs with a balanced product tree.
+pub(crate) fn product_of_small(
+    xs: impl IntoIterator<Item = u64>,
+) -> BigInteger {
+    let mut limbs = Vec::new();
+    let mut acc: u64 = 1;
+    for x in xs {
+        match acc.checked_mul(x) {
+            Some(p) => acc = p,
+            None => {
+                limbs.push(BigInteger::from(acc));
+                acc = x;
+            }
+        }
+    }
+    limbs.push(BigInteger::from(acc));
+    product_tree(&limbs)
+}
+
+/// Multiplies `xs` pairwise in a balanced tree, so that the operands of each
+/// multiplication have similar sizes. With the `parallel` feature, large
+/// subtrees are multiplied concurrently.
+pub(crate) fn product_tree(xs: &[BigInteger]) -> BigInteger {
+    match xs {
+        [] => BigInteger::from(1_u8),
+        [x] => x.clone(),
+        _ => {
+            let (l, r) = xs.split_at(xs.len() / 2);
+            let size = || xs.iter().map(|x| x.len()).sum::<usize>();
+            let (l, r) = if cfg!(feature = "parallel")
+                && size() >= 2 * PARALLEL_MUL_THRESHOLD
+            {
+                join(|| product_tree(l), || product_tree(r))
+            } else {
+                (product_tree(l), product_tree(r))
+            };
+            l * r
+        }
+    }
+}
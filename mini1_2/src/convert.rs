@@ -0,0 +1,134 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+
+use super::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TryFromBigIntError(());
+
+impl Display for TryFromBigIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "out of range integral type conversion attempted")
+    }
+}
+
+impl Error for TryFromBigIntError {}
+
+impl BigInteger {
+    /// Converts to the nearest `f64`, rounding ties to even. Values beyond
+    /// the range of `f64` become infinities.
+    pub fn to_f64(&self) -> f64 {
+        let n = self.len();
+        let magnitude = match n {
+            0 => 0.0,
+            1 => self.digits[0] as f64,
+            _ => {
+                let s = self.digits[n - 1].leading_zeros();
+                let top = shl_bits(self.digits[n - 2..].to_vec(), s)[1];
+                let sticky = self.digits[..n - 2].iter().any(|&d| d != 0)
+                    || self.digits[n - 2] << s != 0;
+                let exp = ((n - 1) * DIGIT_BITS as usize) as i32 - s as i32;
+                ((top | sticky as Digit) as f64) * 2_f64.powi(exp)
+            }
+        };
+        match self.signum() {
+            -1 => -magnitude,
+            _ => magnitude,
+        }
+    }
+
+    /// Converts a finite `f64` by truncating its fractional part toward
+    /// zero. Returns `None` for infinities and NaN.
+    pub fn from_f64(x: f64) -> Option<Self> {
+        if !x.is_finite() {
+            return None;
+        }
+        let bits = x.to_bits();
+        let exp = (bits >> 52 & 0x7ff) as i32;
+        let fraction = bits & ((1 << 52) - 1);
+        let mantissa = match exp {
+            0 => fraction << 1,
+            _ => fraction | 1 << 52,
+        };
+        let exp = exp - 1075;
+        let digits = match exp {
+            ..=-64 => Vec::new(),
+            -63..=-1 => vec![mantissa >> -exp],
+            _ => lsh(
+                shl_bits(vec![mantissa], exp as u32 % DIGIT_BITS),
+                exp as usize / DIGIT_BITS as usize,
+            ),
+        };
+        Some(Self::from_parts(digits, if x < 0.0 { -1 } else { 1 }))
+    }
+}
+
+impl From<bool> for BigInteger {
+    fn from(x: bool) -> Self {
+        Self::from_u128(x as u128, 1)
+    }
+}
+
+macro_rules! impl_unsigned_convert {
+    ($($t:ty),*) => {$(
+        impl From<$t> for BigInteger {
+            fn from(x: $t) -> Self {
+                Self::from_u128(x as u128, 1)
+            }
+        }
+
+        impl TryFrom<&BigInteger> for $t {
+            type Error = TryFromBigIntError;
+
+            fn try_from(x: &BigInteger) -> Result<Self, Self::Error> {
+                match (x.signum(), x.to_u128()) {
+                    (0.., Some(m)) if m <= <$t>::MAX as u128 => Ok(m as $t),
+                    _ => Err(TryFromBigIntError(())),
+                }
+            }
+        }
+
+        impl TryFrom<BigInteger> for $t {
+            type Error = TryFromBigIntError;
+
+            fn try_from(x: BigInteger) -> Result<Self, Self::Error> {
+                <$t>::try_from(&x)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_signed_convert {
+    ($($t:ty),*) => {$(
+        impl From<$t> for BigInteger {
+            fn from(x: $t) -> Self {
+                Self::from_u128(x.unsigned_abs() as u128, x.signum() as i8)
+            }
+        }
+
+        impl TryFrom<&BigInteger> for $t {
+            type Error = TryFromBigIntError;
+
+            fn try_from(x: &BigInteger) -> Result<Self, Self::Error> {
+                match (x.signum(), x.to_u128()) {
+                    (0.., Some(m)) if m <= <$t>::MAX as u128 => Ok(m as $t),
+                    (-1, Some(m)) if m <= <$t>::MIN.unsigned_abs() as u128 => {
+                        Ok((m as i128).wrapping_neg() as $t)
+                    }
+                    _ => Err(TryFromBigIntError(())),
+                }
+            }
+        }
+
+        impl TryFrom<BigInteger> for $t {
+            type Error = TryFromBigIntError;
+
+            fn try_from(x: BigInteger) -> Result<Self, Self::Error> {
+                <$t>::try_from(&x)
+            }
+        }
+    )*};
+}
+
+impl_unsigned_convert!(u8, u16, u32, u64, u128, usize);
+impl_signed_convert!(i8, i16, i32, i64, i128, isize);
@@ -0,0 +1,76 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+
+use super::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrtError {
+    ZeroModulus { index: usize },
+    Inconsistent,
+}
+
+impl Display for CrtError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ZeroModulus { index } => write!(f, "zero modulus at {index}"),
+            Self::Inconsistent => write!(f, "inconsistent congruences"),
+        }
+    }
+}
+
+impl Error for CrtError {}
+
+impl BigInteger {
+    /// Solves `x ≡ residue (mod |modulus|)` for every pair, returning the
+    /// unique solution in `0..M`, where `M` is the lcm of the moduli. The
+    /// moduli need not be coprime, but then the residues must agree modulo
+    /// their gcds. Pairs are merged in a balanced tree, so the moduli being
+    /// combined have similar sizes.
+    pub fn crt(congruences: &[(Self, Self)]) -> Result<Self, CrtError> {
+        Ok(solve(congruences)?.0)
+    }
+
+    /// Like [`crt`](Self::crt), but returns the solution in `(-M/2, M/2]`.
+    pub fn crt_symmetric(
+        congruences: &[(Self, Self)],
+    ) -> Result<Self, CrtError> {
+        let (x, m) = solve(congruences)?;
+        match (&x << 1) > m {
+            true => Ok(x - m),
+            false => Ok(x),
+        }
+    }
+}
+
+/// Rejects zero moduli, then returns the solution in `0..M` together with `M`.
+fn solve(
+    congruences: &[(BigInteger, BigInteger)],
+) -> Result<(BigInteger, BigInteger), CrtError> {
+    if let Some(index) = congruences.iter().position(|(_, m)| m.signum() == 0) {
+        return Err(CrtError::ZeroModulus { index });
+    }
+    crt_tree(congruences)
+}
+
+fn crt_tree(
+    congruences: &[(BigInteger, BigInteger)],
+) -> Result<(BigInteger, BigInteger), CrtError> {
+    match congruences {
+        [] => Ok((BigInteger::ZERO, BigInteger::from(1_u8))),
+        [(r, m)] => Ok((r.rem_euclid(m), m.clone().abs())),
+        _ => {
+            let (l, r) = congruences.split_at(congruences.len() / 2);
+            let ((x, m), (y, n)) = (crt_tree(l)?, crt_tree(r)?);
+            let (g, u, _) = m.extended_gcd(&n);
+            let (q, rem) = (y - &x).div_rem(&g);
+            if rem.signum() != 0 {
+                return Err(CrtError::Inconsistent);
+            }
+            // x + m * t satisfies both when m * t ≡ y - x (mod n), that is
+            // t ≡ u * (y - x) / g (mod n / g).
+            let n = n / &g;
+            let t = (q * u).rem_euclid(&n);
+            Ok((x + &m * t, m * n))
+        }
+    }
+}
@@ -0,0 +1,491 @@
+use std::error::Error;
+use std::fmt::{self, Debug, Display, LowerExp, UpperExp};
+use std::str::FromStr;
+
+use super::*;
+
+/// Largest absolute scale accepted by `FromStr`, so that any two parsed
+/// values can be aligned.
+pub const MAX_PARSED_SCALE: i64 = i32::MAX as i64;
+
+/// Significant digits used by the `/` operator.
+pub const DEFAULT_DIV_PRECISION: u64 = 34;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round to nearest, ties to even.
+    HalfEven,
+    /// Round to nearest, ties away from zero.
+    HalfUp,
+    /// Round towards negative infinity.
+    Floor,
+    /// Round towards positive infinity.
+    Ceiling,
+    /// Round towards zero.
+    Truncate,
+}
+
+/// A decimal number `unscaled * 10^(-scale)`. Comparison is by value, so
+/// `1.0 == 1.00`.
+#[derive(Clone)]
+pub struct BigDecimal {
+    unscaled: BigInteger,
+    scale: i64,
+}
+
+impl BigDecimal {
+    pub fn new(unscaled: BigInteger, scale: i64) -> Self {
+        BigDecimal { unscaled, scale }
+    }
+
+    pub fn unscaled(&self) -> &BigInteger {
+        &self.unscaled
+    }
+
+    pub fn scale(&self) -> i64 {
+        self.scale
+    }
+
+    pub fn signum(&self) -> i8 {
+        self.unscaled.signum()
+    }
+
+    /// Number of decimal digits in the unscaled value, 1 for zero.
+    pub fn precision(&self) -> u64 {
+        match self.signum() {
+            0 => 1,
+            _ => self.unscaled.clone().abs().ilog10() + 1,
+        }
+    }
+
+    pub fn abs(self) -> Self {
+        BigDecimal { unscaled: self.unscaled.abs(), scale: self.scale }
+    }
+
+    /// Returns the value with the given scale, rounding if digits are lost.
+    pub fn with_scale(&self, scale: i64, mode: RoundingMode) -> Self {
+        let k = scale.abs_diff(self.scale);
+        let unscaled = match scale >= self.scale {
+            true => &self.unscaled * &pow10(k),
+            false => round_quotient(&self.unscaled, &pow10(k), mode),
+        };
+        BigDecimal { unscaled, scale }
+    }
+
+    /// Rounds to at most `precision` significant digits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `precision` is zero.
+    pub fn round(&self, precision: u64, mode: RoundingMode) -> Self {
+        assert!(precision > 0, "precision must be positive");
+        let digits = self.precision();
+        if digits <= precision {
+            return self.clone();
+        }
+        let r = self.with_scale(self.scale - (digits - precision) as i64, mode);
+        match r.precision() > precision {
+            true => r.with_scale(r.scale - 1, mode),
+            false => r,
+        }
+    }
+
+    /// Removes trailing zeros from the unscaled value.
+    pub fn normalized(&self) -> Self {
+        self.strip_zeros(i64::MIN)
+    }
+
+    fn strip_zeros(&self, min_scale: i64) -> Self {
+        if self.signum() == 0 {
+            return BigDecimal { unscaled: BigInteger::ZERO, scale: 0 };
+        }
+        let (mut digits, mut scale) =
+            (self.unscaled.digits.to_vec(), self.scale);
+        while scale > min_scale && rem_small(&digits, 10) == 0 {
+            div_small(&mut digits, 10);
+            trunc_in_place(&mut digits);
+            scale -= 1;
+        }
+        let unscaled = BigInteger::from_parts(digits, self.signum());
+        BigDecimal { unscaled, scale }
+    }
+
+    /// Divides to `precision` significant digits, dropping trailing zeros
+    /// down to the scale `self.scale() - rhs.scale()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero or `precision` is zero.
+    pub fn div_to_precision(
+        &self,
+        rhs: &Self,
+        precision: u64,
+        mode: RoundingMode,
+    ) -> Self {
+        assert!(rhs.signum() != 0, "attempt to divide by zero");
+        assert!(precision > 0, "precision must be positive");
+        let scale = self.scale.checked_sub(rhs.scale);
+        let scale = scale.expect("decimal scale out of range");
+        if self.signum() == 0 {
+            return BigDecimal { unscaled: BigInteger::ZERO, scale };
+        }
+        let (n, d) = match rhs.signum() {
+            -1 => (-self.unscaled.clone(), -rhs.unscaled.clone()),
+            _ => (self.unscaled.clone(), rhs.unscaled.clone()),
+        };
+        let shift = precision as i64 + rhs.precision() as i64
+            - self.precision() as i64
+            + 1;
+        let q = BigDecimal {
+            unscaled: scaled_quotient(&n, &d, shift, RoundingMode::Truncate),
+            scale: scale + shift,
+        };
+        let shift = shift - (q.precision() - precision) as i64;
+        let q = BigDecimal {
+            unscaled: scaled_quotient(&n, &d, shift, mode),
+            scale: scale + shift,
+        };
+        q.round(precision, mode).strip_zeros(scale)
+    }
+
+    /// Square root to `precision` significant digits, dropping trailing
+    /// zeros down to half the scale of `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is negative or `precision` is zero.
+    pub fn sqrt(&self, precision: u64, mode: RoundingMode) -> Self {
+        assert!(self.signum() >= 0, "square root of negative number");
+        assert!(precision > 0, "precision must be positive");
+        let preferred = self.scale.div_euclid(2);
+        if self.signum() == 0 {
+            return BigDecimal { unscaled: BigInteger::ZERO, scale: preferred };
+        }
+        let mut e = 2 * (precision as i64 + 2) - self.precision() as i64;
+        if (e + self.scale) % 2 != 0 {
+            e += 1;
+        }
+        let (n, exact) = match e {
+            0.. => (&self.unscaled * &pow10(e.unsigned_abs()), true),
+            _ => {
+                let (n, r) = self.unscaled.div_rem(&pow10(e.unsigned_abs()));
+                (n, r.signum() == 0)
+            }
+        };
+        let root = n.isqrt();
+        let exact = exact && &root * &root == n;
+        let unscaled = root * 10_u8 + !exact as u8;
+        let r = BigDecimal { unscaled, scale: (e + self.scale) / 2 + 1 };
+        r.round(precision, mode).strip_zeros(preferred)
+    }
+
+    fn aligned(&self, other: &Self) -> (BigInteger, BigInteger, i64) {
+        let scale = max(self.scale, other.scale);
+        let align = |x: &Self| &x.unscaled * &pow10(scale.abs_diff(x.scale));
+        (align(self), align(other), scale)
+    }
+
+    /// Exponent of the leading digit, so that `10^e <= |self| < 10^(e + 1)`.
+    fn exponent(&self) -> i128 {
+        self.precision() as i128 - 1 - self.scale as i128
+    }
+
+    /// With a precision, rounds to that many digits after the point with ties
+    /// to even, padding with zeros.
+    fn fmt_exp(&self, f: &mut fmt::Formatter<'_>, e: char) -> fmt::Result {
+        let x = match f.precision() {
+            Some(p) => self.round(p as u64 + 1, RoundingMode::HalfEven),
+            None => self.clone(),
+        };
+        let mut digits = x.unscaled.clone().abs().to_string();
+        let exp = digits.len() as i64 - 1 - x.scale;
+        if let Some(p) = f.precision() {
+            digits += &"0".repeat((p + 1).saturating_sub(digits.len()));
+        }
+        let s = match digits.split_at(1) {
+            (head, "") => format!("{head}{e}{exp}"),
+            (head, tail) => format!("{head}.{tail}{e}{exp}"),
+        };
+        f.pad_integral(self.signum() >= 0, "", &s)
+    }
+}
+
+/// # Panics
+///
+/// Panics if `n` does not fit in a `u32`, which happens when aligning values
+/// whose scales are too far apart to represent.
+fn pow10(n: u64) -> BigInteger {
+    let n = u32::try_from(n).expect("decimal scale difference out of range");
+    BigInteger::from(10_u8).pow(n)
+}
+
+/// Divides `n` by a positive `d`, rounding the quotient with `mode`.
+fn round_quotient(
+    n: &BigInteger,
+    d: &BigInteger,
+    mode: RoundingMode,
+) -> BigInteger {
+    let (q, r) = n.div_rem(d);
+    if r.signum() == 0 {
+        return q;
+    }
+    let away = match mode {
+        RoundingMode::Truncate => false,
+        RoundingMode::Floor => n.signum() < 0,
+        RoundingMode::Ceiling => n.signum() > 0,
+        RoundingMode::HalfUp | RoundingMode::HalfEven => {
+            match (r.abs() << 1).cmp_abs(d) {
+                Ordering::Less => false,
+                Ordering::Greater => true,
+                Ordering::Equal => mode == RoundingMode::HalfUp || q.bit(0),
+            }
+        }
+    };
+    match away {
+        true => q + n.signum(),
+        false => q,
+    }
+}
+
+/// Rounds `n * 10^k / d` to an integer.
+fn scaled_quotient(
+    n: &BigInteger,
+    d: &BigInteger,
+    k: i64,
+    mode: RoundingMode,
+) -> BigInteger {
+    match k {
+        0.. => round_quotient(&(n * &pow10(k.unsigned_abs())), d, mode),
+        _ => round_quotient(n, &(d * &pow10(k.unsigned_abs())), mode),
+    }
+}
+
+impl From<BigInteger> for BigDecimal {
+    fn from(unscaled: BigInteger) -> Self {
+        BigDecimal { unscaled, scale: 0 }
+    }
+}
+
+impl Default for BigDecimal {
+    fn default() -> Self {
+        BigInteger::ZERO.into()
+    }
+}
+
+impl PartialEq for BigDecimal {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for BigDecimal {}
+
+impl PartialOrd for BigDecimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigDecimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.signum() != other.signum() || self.signum() == 0 {
+            return self.signum().cmp(&other.signum());
+        }
+        // Values with different leading exponents are ordered without
+        // aligning, which could need an arbitrarily large power of ten.
+        let by_exponent = self.exponent().cmp(&other.exponent());
+        if by_exponent != Ordering::Equal {
+            return match self.signum() {
+                1 => by_exponent,
+                _ => by_exponent.reverse(),
+            };
+        }
+        let (x, y, _) = self.aligned(other);
+        x.cmp(&y)
+    }
+}
+
+impl Hash for BigDecimal {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let x = self.normalized();
+        x.unscaled.hash(state);
+        x.scale.hash(state);
+    }
+}
+
+impl Neg for BigDecimal {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        BigDecimal { unscaled: -self.unscaled, scale: self.scale }
+    }
+}
+
+impl Neg for &BigDecimal {
+    type Output = BigDecimal;
+
+    fn neg(self) -> Self::Output {
+        -self.clone()
+    }
+}
+
+impl Add<&BigDecimal> for &BigDecimal {
+    type Output = BigDecimal;
+
+    fn add(self, rhs: &BigDecimal) -> Self::Output {
+        let (x, y, scale) = self.aligned(rhs);
+        BigDecimal { unscaled: x + y, scale }
+    }
+}
+
+impl Sub<&BigDecimal> for &BigDecimal {
+    type Output = BigDecimal;
+
+    fn sub(self, rhs: &BigDecimal) -> Self::Output {
+        let (x, y, scale) = self.aligned(rhs);
+        BigDecimal { unscaled: x - y, scale }
+    }
+}
+
+impl Mul<&BigDecimal> for &BigDecimal {
+    type Output = BigDecimal;
+
+    fn mul(self, rhs: &BigDecimal) -> Self::Output {
+        let unscaled = &self.unscaled * &rhs.unscaled;
+        let scale = self.scale.checked_add(rhs.scale);
+        BigDecimal {
+            unscaled,
+            scale: scale.expect("decimal scale out of range"),
+        }
+    }
+}
+
+impl Div<&BigDecimal> for &BigDecimal {
+    type Output = BigDecimal;
+
+    /// Divides to [`DEFAULT_DIV_PRECISION`] digits with
+    /// [`RoundingMode::HalfEven`].
+    fn div(self, rhs: &BigDecimal) -> Self::Output {
+        self.div_to_precision(
+            rhs,
+            DEFAULT_DIV_PRECISION,
+            RoundingMode::HalfEven,
+        )
+    }
+}
+
+macro_rules! decimal_assign {
+    ($($Assign:ident, $assign:ident, $op:ident;)*) => {$(
+        impl $Assign<&BigDecimal> for BigDecimal {
+            fn $assign(&mut self, rhs: &BigDecimal) {
+                *self = (&*self).$op(rhs);
+            }
+        }
+    )*};
+}
+
+decimal_assign! {
+    AddAssign, add_assign, add;
+    SubAssign, sub_assign, sub;
+    MulAssign, mul_assign, mul;
+    DivAssign, div_assign, div;
+}
+
+forward_binop_to_ref!(BigDecimal, Add, add);
+forward_binop_to_ref!(BigDecimal, Sub, sub);
+forward_binop_to_ref!(BigDecimal, Mul, mul);
+forward_binop_to_ref!(BigDecimal, Div, div);
+forward_assign!(BigDecimal, AddAssign, add_assign);
+forward_assign!(BigDecimal, SubAssign, sub_assign);
+forward_assign!(BigDecimal, MulAssign, mul_assign);
+forward_assign!(BigDecimal, DivAssign, div_assign);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseDecimalError {
+    Integer(ParseBigIntError),
+    InvalidExponent { offset: usize },
+}
+
+impl Display for ParseDecimalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Integer(e) => Display::fmt(e, f),
+            Self::InvalidExponent { offset } => {
+                write!(f, "invalid exponent at offset {offset}")
+            }
+        }
+    }
+}
+
+impl Error for ParseDecimalError {}
+
+impl FromStr for BigDecimal {
+    type Err = ParseDecimalError;
+
+    /// Parses a decimal number with an optional fraction and exponent, such
+    /// as `-12.5`, `.5` or `6.02e23`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (mantissa, exp) = match s.find(['e', 'E']) {
+            Some(i) => {
+                let exp = s[i + 1..].parse::<i64>().map_err(|_| {
+                    ParseDecimalError::InvalidExponent { offset: i + 1 }
+                })?;
+                (&s[..i], exp)
+            }
+            None => (s, 0),
+        };
+        let (int, frac) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        let (unscaled, digits) =
+            parse_fraction(int, frac).map_err(ParseDecimalError::Integer)?;
+        let scale = (digits as i64)
+            .checked_sub(exp)
+            .filter(|scale| scale.abs() <= MAX_PARSED_SCALE)
+            .ok_or(ParseDecimalError::InvalidExponent {
+                offset: mantissa.len() + 1,
+            })?;
+        Ok(BigDecimal { unscaled, scale })
+    }
+}
+
+impl Display for BigDecimal {
+    /// Prints the value without an exponent. With a precision, rounds to that
+    /// many decimal places with ties to even.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let x = match f.precision() {
+            Some(p) => self.with_scale(p as i64, RoundingMode::HalfEven),
+            None => self.clone(),
+        };
+        let mut digits = x.unscaled.clone().abs().to_string();
+        let s = match x.scale {
+            ..=0 if x.signum() == 0 => digits,
+            ..=0 => digits + &"0".repeat(-x.scale as usize),
+            scale => {
+                let scale = scale as usize;
+                if digits.len() <= scale {
+                    digits = "0".repeat(scale + 1 - digits.len()) + &digits;
+                }
+                let (int, frac) = digits.split_at(digits.len() - scale);
+                format!("{int}.{frac}")
+            }
+        };
+        f.pad_integral(x.signum() >= 0, "", &s)
+    }
+}
+
+impl LowerExp for BigDecimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_exp(f, 'e')
+    }
+}
+
+impl UpperExp for BigDecimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_exp(f, 'E')
+    }
+}
+
+impl Debug for BigDecimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
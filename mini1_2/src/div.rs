@@ -0,0 +1,198 @@
+use super::*;
+
+pub const BURNIKEL_ZIEGLER_THRESHOLD: usize = 32;
+
+impl BigInteger {
+    /// Truncated division: the quotient is rounded toward zero and the
+    /// remainder has the sign of `self`. Returns `None` if `rhs` is zero.
+    pub fn checked_div_rem(&self, rhs: &Self) -> Option<(Self, Self)> {
+        if rhs.signum() == 0 {
+            return None;
+        }
+        if let (Some(x), Some(y)) = (self.to_u128(), rhs.to_u128()) {
+            return Some((
+                Self::from_u128(x / y, self.signum * rhs.signum),
+                Self::from_u128(x % y, self.signum),
+            ));
+        }
+        let (q, r) = div_rem_magnitude(self.digits.to_vec(), &rhs.digits);
+        Some((
+            Self::from_parts(q, self.signum * rhs.signum),
+            Self::from_parts(r, self.signum),
+        ))
+    }
+
+    /// Truncated division, see [`BigInteger::checked_div_rem`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        self.checked_div_rem(rhs).expect("attempt to divide by zero")
+    }
+
+    /// Euclidean division: the remainder is always non-negative, so
+    /// `0 <= r < |rhs|`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    pub fn div_rem_euclid(&self, rhs: &Self) -> (Self, Self) {
+        let (q, r) = self.div_rem(rhs);
+        match (r.signum(), rhs.signum()) {
+            (-1, 1) => (q - 1_u8, r + rhs),
+            (-1, -1) => (q + 1_u8, r - rhs),
+            _ => (q, r),
+        }
+    }
+
+    /// Euclidean quotient, rounded so that the remainder is non-negative.
+    /// Panics if `rhs` is zero.
+    pub fn div_euclid(&self, rhs: &Self) -> Self {
+        self.div_rem_euclid(rhs).0
+    }
+
+    /// Euclidean remainder, always in `0..|rhs|`. Panics if `rhs` is zero.
+    pub fn rem_euclid(&self, rhs: &Self) -> Self {
+        self.div_rem_euclid(rhs).1
+    }
+
+    /// Floored division: the quotient is rounded toward negative infinity
+    /// and the remainder has the sign of `rhs`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    pub fn div_mod_floor(&self, rhs: &Self) -> (Self, Self) {
+        let (q, r) = self.div_rem(rhs);
+        if r.signum() * rhs.signum() < 0 {
+            (q - 1_u8, r + rhs)
+        } else {
+            (q, r)
+        }
+    }
+
+    /// Quotient rounded toward negative infinity. Panics if `rhs` is zero.
+    pub fn div_floor(&self, rhs: &Self) -> Self {
+        self.div_mod_floor(rhs).0
+    }
+
+    /// Floored remainder, with the sign of `rhs`. Panics if `rhs` is zero.
+    pub fn mod_floor(&self, rhs: &Self) -> Self {
+        self.div_mod_floor(rhs).1
+    }
+}
+
+impl Div<&BigInteger> for &BigInteger {
+    type Output = BigInteger;
+
+    fn div(self, rhs: &BigInteger) -> Self::Output {
+        self.div_rem(rhs).0
+    }
+}
+
+impl Rem<&BigInteger> for &BigInteger {
+    type Output = BigInteger;
+
+    fn rem(self, rhs: &BigInteger) -> Self::Output {
+        self.div_rem(rhs).1
+    }
+}
+
+impl DivAssign<&BigInteger> for BigInteger {
+    fn div_assign(&mut self, rhs: &BigInteger) {
+        *self = &*self / rhs;
+    }
+}
+
+impl RemAssign<&BigInteger> for BigInteger {
+    fn rem_assign(&mut self, rhs: &BigInteger) {
+        *self = &*self % rhs;
+    }
+}
+
+forward_assign!(DivAssign, div_assign);
+forward_assign!(RemAssign, rem_assign);
+
+forward_binop_to_ref!(Div, div);
+forward_binop_to_ref!(Rem, rem);
+
+pub fn division(x: BigInteger, y: BigInteger) -> BigInteger {
+    assert!(x.signum() >= 0);
+    assert!(y.signum() > 0);
+    x / y
+}
+
+fn div_rem_magnitude(x: Vec<Digit>, y: &[Digit]) -> (Vec<Digit>, Vec<Digit>) {
+    let n = y.len();
+    if n < BURNIKEL_ZIEGLER_THRESHOLD
+        || x.len() < n + BURNIKEL_ZIEGLER_THRESHOLD
+    {
+        return long_division(x, y);
+    }
+
+    let s = y.last().unwrap().leading_zeros();
+    let b = BigInteger::from_parts(shl_bits(y.to_vec(), s), 1);
+    let a = shl_bits(x, s);
+    let mut chunks = Vec::new();
+    let mut r = BigInteger::ZERO;
+    for chunk in a.chunks(n).rev() {
+        let a = r.shift(n) + BigInteger::from_parts(chunk.to_vec(), 1);
+        let (q, rest) = div_2n_1n(a, &b, n);
+        chunks.push(q);
+        r = rest;
+    }
+
+    let mut q = Vec::with_capacity(a.len());
+    for chunk in chunks.into_iter().rev() {
+        let mut digits = chunk.digits.into_vec();
+        digits.resize(n, 0);
+        q.extend(digits);
+    }
+    (trunc(q), shr_bits(r.digits.into_vec(), s))
+}
+
+fn div_2n_1n(
+    a: BigInteger,
+    b: &BigInteger,
+    n: usize,
+) -> (BigInteger, BigInteger) {
+    if n < BURNIKEL_ZIEGLER_THRESHOLD {
+        let (q, r) = long_division(a.digits.into_vec(), &b.digits);
+        return (BigInteger::from_parts(q, 1), BigInteger::from_parts(r, 1));
+    }
+    if n % 2 == 1 {
+        let (q, r) = div_2n_1n(a.shift(1), &b.clone().shift(1), n + 1);
+        return (q, r.split_on(1).1);
+    }
+
+    let half = n / 2;
+    let (b2, b1) = b.halves(half);
+    let (a4, a) = a.split_on(half);
+    let (a3, a12) = a.split_on(half);
+    let (q1, r) = div_3n_2n(a12, a3, b, &b1, &b2, half);
+    let (q2, r) = div_3n_2n(r, a4, b, &b1, &b2, half);
+    (q1.shift(half) + q2, r)
+}
+
+fn div_3n_2n(
+    a12: BigInteger,
+    a3: BigInteger,
+    b: &BigInteger,
+    b1: &BigInteger,
+    b2: &BigInteger,
+    n: usize,
+) -> (BigInteger, BigInteger) {
+    let (mut q, c) = if a12.digits.get(n..) == Some(&b1.digits) {
+        let q = BigInteger::from_parts(vec![Digit::MAX; n], 1);
+        (q, a12 - b1.clone().shift(n) + b1)
+    } else {
+        div_2n_1n(a12, b1, n)
+    };
+    let mut r = c.shift(n) + a3 - &q * b2;
+    while r.signum() < 0 {
+        q -= 1_u8;
+        r += b;
+    }
+    (q, r)
+}
@@ -0,0 +1,72 @@
+use std::fmt::{self, Binary, Debug, Display, LowerHex, Octal, UpperHex};
+
+use super::*;
+
+impl BigInteger {
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        let digits = self.magnitude_str_radix(radix);
+        match self.signum() {
+            -1 => format!("-{digits}"),
+            _ => digits,
+        }
+    }
+
+    fn magnitude_str_radix(&self, radix: u32) -> String {
+        assert!((2..=36).contains(&radix), "radix must be in 2..=36");
+        if self.signum() == 0 {
+            return "0".to_string();
+        }
+        to_radix_digits(self, radix)
+            .into_iter()
+            .rev()
+            .map(|d| char::from_digit(d as u32, radix).unwrap())
+            .collect()
+    }
+
+    fn fmt_radix(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        radix: u32,
+        prefix: &str,
+    ) -> fmt::Result {
+        let digits = self.magnitude_str_radix(radix);
+        f.pad_integral(self.signum() >= 0, prefix, &digits)
+    }
+}
+
+impl Display for BigInteger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_radix(f, 10, "")
+    }
+}
+
+impl Debug for BigInteger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl LowerHex for BigInteger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_radix(f, 16, "0x")
+    }
+}
+
+impl UpperHex for BigInteger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = self.magnitude_str_radix(16).to_uppercase();
+        f.pad_integral(self.signum() >= 0, "0x", &digits)
+    }
+}
+
+impl Octal for BigInteger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_radix(f, 8, "0o")
+    }
+}
+
+impl Binary for BigInteger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_radix(f, 2, "0b")
+    }
+}
@@ -0,0 +1,73 @@
+use super::*;
+
+impl BigInteger {
+    /// Greatest common divisor, always non-negative. Uses binary gcd and
+    /// falls back to a remainder step when the operands differ in length.
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.clone().abs(), other.clone().abs());
+        let shift = match (a.trailing_zeros(), b.trailing_zeros()) {
+            (None, _) => return b,
+            (_, None) => return a,
+            (Some(za), Some(zb)) => min(za, zb) as usize,
+        };
+        loop {
+            match (a.trailing_zeros(), b.trailing_zeros()) {
+                (None, _) => return b << shift,
+                (_, None) => return a << shift,
+                (Some(za), Some(zb)) => {
+                    a >>= za as usize;
+                    b >>= zb as usize;
+                }
+            }
+            if a > b {
+                mem::swap(&mut a, &mut b);
+            }
+            if a.len() + 1 < b.len() {
+                b %= &a;
+            } else {
+                b -= &a;
+            }
+        }
+    }
+
+    /// Least common multiple, always non-negative.
+    pub fn lcm(&self, other: &Self) -> Self {
+        if self.signum() == 0 || other.signum() == 0 {
+            return Self::ZERO;
+        }
+        (self / &self.gcd(other) * other).abs()
+    }
+
+    /// Returns `(g, x, y)` such that `self * x + other * y == g`, where `g`
+    /// is the non-negative greatest common divisor.
+    pub fn extended_gcd(&self, other: &Self) -> (Self, Self, Self) {
+        let (mut r0, mut r1) = (self.clone(), other.clone());
+        let (mut s0, mut s1) = (Self::from(1_u8), Self::ZERO);
+        let (mut t0, mut t1) = (Self::ZERO, Self::from(1_u8));
+        while r1.signum() != 0 {
+            let (q, r) = r0.div_rem(&r1);
+            r0 = mem::replace(&mut r1, r);
+            let s = s0 - &q * &s1;
+            s0 = mem::replace(&mut s1, s);
+            let t = t0 - &q * &t1;
+            t0 = mem::replace(&mut t1, t);
+        }
+        match r0.signum() {
+            -1 => (-r0, -s0, -t0),
+            _ => (r0, s0, t0),
+        }
+    }
+
+    /// Inverse of `self` modulo `|modulus|` in `0..|modulus|`, or `None` if
+    /// it does not exist.
+    pub fn mod_inverse(&self, modulus: &Self) -> Option<Self> {
+        if modulus.signum() == 0 {
+            return None;
+        }
+        let (g, x, _) = self.extended_gcd(modulus);
+        match g == Self::from(1_u8) {
+            true => Some(x.rem_euclid(modulus)),
+            false => None,
+        }
+    }
+}
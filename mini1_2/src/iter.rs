@@ -0,0 +1,38 @@
+use std::iter::{Product, Sum};
+
+use super::combinatorics::product_tree;
+use super::*;
+
+impl Sum for BigInteger {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl<'a> Sum<&'a BigInteger> for BigInteger {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl BigInteger {
+    /// Multiplies all values with a balanced product tree, whose subtrees
+    /// run on separate threads with the `parallel` feature.
+    pub fn product<I: IntoIterator<Item = BigInteger>>(iter: I) -> Self {
+        product_tree(&iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
+/// Multiplies with a balanced product tree.
+impl Product for BigInteger {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        product_tree(&iter.collect::<Vec<_>>())
+    }
+}
+
+/// Multiplies with a balanced product tree.
+impl<'a> Product<&'a BigInteger> for BigInteger {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        product_tree(&iter.cloned().collect::<Vec<_>>())
+    }
+}
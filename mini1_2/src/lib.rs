@@ -1,38 +1,98 @@
 use std::cmp::*;
+use std::hash::{Hash, Hasher};
+use std::mem;
 use std::ops::*;
-use std::str::FromStr;
 
+#[macro_use]
+mod macros;
+mod bits;
+mod bytes;
+mod combinatorics;
+mod convert;
+mod crt;
+mod decimal;
+mod div;
+mod fmt;
+mod gcd;
+mod iter;
+mod limbs;
+mod modular;
+mod mul;
+#[cfg(feature = "num-traits")]
+mod num;
 mod ops;
+mod parse;
+mod prim;
+mod prime;
+mod radix;
+#[cfg(feature = "rand")]
+mod random;
+mod rational;
+mod roots;
+#[cfg(feature = "serde")]
+mod serialize;
 
+pub use convert::*;
+pub use crt::*;
+pub use decimal::*;
+pub use div::*;
+#[cfg(feature = "bench-baseline")]
+#[doc(hidden)]
+pub use limbs::set_inline_limbs;
+pub use limbs::INLINE_LIMBS;
+use limbs::*;
+pub use modular::*;
+pub use mul::*;
 use ops::*;
+pub use parse::*;
+pub use radix::*;
+pub use rational::*;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Clone)]
 pub struct BigInteger {
-    digits: Vec<i8>,
+    digits: Limbs,
     signum: i8,
 }
 
 impl BigInteger {
-    pub const ZERO: BigInteger = BigInteger { digits: Vec::new(), signum: 0 };
+    pub const ZERO: BigInteger = BigInteger { digits: Limbs::new(), signum: 0 };
 
-    fn from_parts(digits: Vec<i8>, factor: i8) -> Self {
-        let digits = trunc(digits);
+    fn from_parts(digits: Vec<Digit>, factor: i8) -> Self {
+        let digits = Limbs::from(trunc(digits));
         let signum = (min(digits.len(), 1) as i8) * factor;
         BigInteger { digits, signum }
     }
 
+    fn from_u128(x: u128, factor: i8) -> Self {
+        let signum = min(x, 1) as i8 * factor;
+        BigInteger { digits: Limbs::from_u128(x), signum }
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        match self.digits[..] {
+            [] => Some(0),
+            [lo] => Some(lo as u128),
+            [lo, hi] => Some((hi as u128) << DIGIT_BITS | lo as u128),
+            _ => None,
+        }
+    }
+
     pub fn last_digit(&self) -> i8 {
-        self.digits.last().copied().unwrap_or(0)
+        to_radix_digits(self, 10).last().copied().unwrap_or(0) as i8
     }
 
     pub fn signum(&self) -> i8 {
-        if *self == Self::ZERO {
+        if self.digits.is_empty() {
             0
         } else {
             self.signum
         }
     }
 
+    pub fn cmp_abs(&self, other: &Self) -> Ordering {
+        compare(&self.digits, &other.digits)
+    }
+
     pub fn abs(self) -> Self {
         match self.signum() {
             -1 => -self,
@@ -48,13 +108,59 @@ impl BigInteger {
         if self.len() <= n {
             return (self, Default::default());
         }
-        let mut ls = self.digits;
+        let mut ls = self.digits.into_vec();
         let bs = ls.drain(n..).collect();
+        (Self::from_parts(ls, self.signum), Self::from_parts(bs, self.signum))
+    }
+
+    fn halves(&self, n: usize) -> (Self, Self) {
+        let n = min(n, self.len());
         (
-            BigInteger { digits: ls, signum: self.signum },
-            BigInteger { digits: bs, signum: self.signum },
+            Self::from_parts(self.digits[..n].to_vec(), 1),
+            Self::from_parts(self.digits[n..].to_vec(), 1),
         )
     }
+
+    fn add_digits(&mut self, digits: &[Digit], signum: i8) {
+        if signum == 0 {
+            return;
+        }
+        if let (Some(x), [] | [_] | [_, _]) = (self.to_u128(), digits) {
+            let y = digits
+                .iter()
+                .rev()
+                .fold(0, |y, &d| y << DIGIT_BITS | d as u128);
+            let sum = match self.signum {
+                0 => Some((y, signum)),
+                s if s == signum => x.checked_add(y).map(|z| (z, s)),
+                s if x >= y => Some((x - y, s)),
+                _ => Some((y - x, signum)),
+            };
+            if let Some((z, s)) = sum {
+                *self = Self::from_u128(z, s);
+                return;
+            }
+        }
+        let mut x = mem::take(&mut self.digits).into_vec();
+        if self.signum == 0 {
+            x.extend_from_slice(digits);
+            self.signum = signum;
+        } else if self.signum == signum {
+            add_assign(&mut x, digits);
+        } else if compare(&x, digits) == Ordering::Less {
+            rsub_assign(&mut x, digits);
+            self.signum = signum;
+        } else {
+            sub_assign(&mut x, digits);
+            self.signum *= min(x.len(), 1) as i8;
+        }
+        self.digits = x.into();
+    }
+
+    fn shift(mut self, n: usize) -> Self {
+        self.digits = lsh(self.digits.into_vec(), n).into();
+        self
+    }
 }
 
 impl Default for BigInteger {
@@ -63,33 +169,6 @@ impl Default for BigInteger {
     }
 }
 
-impl FromStr for BigInteger {
-    type Err = char;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut signum = 1;
-        let digits = s
-            .chars()
-            .rev()
-            .map(|c| match c {
-                d @ '0'..='9' => {
-                    Ok(unsafe { d.to_digit(10).unwrap_unchecked() } as i8)
-                }
-                '-' => {
-                    if signum == 1 {
-                        signum *= -1;
-                        Ok(0)
-                    } else {
-                        Err('-')
-                    }
-                }
-                c => Err(c),
-            })
-            .collect::<Result<_, _>>()?;
-        Ok(Self::from_parts(digits, signum))
-    }
-}
-
 impl Neg for BigInteger {
     type Output = Self;
 
@@ -99,118 +178,96 @@ impl Neg for BigInteger {
     }
 }
 
-impl Add for BigInteger {
-    type Output = Self;
+impl AddAssign<&BigInteger> for BigInteger {
+    fn add_assign(&mut self, rhs: &BigInteger) {
+        self.add_digits(&rhs.digits, rhs.signum);
+    }
+}
 
-    fn add(self, rhs: Self) -> Self::Output {
-        let (lhs, rhs) =
-            if self.signum >= rhs.signum { (self, rhs) } else { (rhs, self) };
-        match (lhs.signum, rhs.signum) {
-            (_, 0) => lhs,
-            (0, _) => rhs,
-            (1, 1) => BigInteger {
-                digits: addition(lhs.digits, rhs.digits),
-                signum: 1,
-            },
-            (1, -1) => {
-                let (digits, factor) = substraction(lhs.digits, rhs.digits);
-                Self::from_parts(digits, factor)
-            }
-            (-1, -1) => -((-lhs) + (-rhs)),
-            _ => unreachable!(),
-        }
+impl SubAssign<&BigInteger> for BigInteger {
+    fn sub_assign(&mut self, rhs: &BigInteger) {
+        self.add_digits(&rhs.digits, -rhs.signum);
     }
 }
 
-impl Sub for BigInteger {
-    type Output = Self;
+impl MulAssign<&BigInteger> for BigInteger {
+    fn mul_assign(&mut self, rhs: &BigInteger) {
+        *self = &*self * rhs;
+    }
+}
 
-    fn sub(self, rhs: Self) -> Self::Output {
-        self + (-rhs)
+forward_assign!(AddAssign, add_assign);
+forward_assign!(SubAssign, sub_assign);
+forward_assign!(MulAssign, mul_assign);
+
+forward_binop_to_assign!(Add, add, add_assign);
+forward_binop_to_assign!(Sub, sub, sub_assign);
+forward_binop_to_ref!(Mul, mul);
+
+impl Add<BigInteger> for &BigInteger {
+    type Output = BigInteger;
+
+    fn add(self, rhs: BigInteger) -> Self::Output {
+        rhs + self
     }
 }
 
-impl Mul for BigInteger {
+impl Add<&BigInteger> for &BigInteger {
     type Output = BigInteger;
 
-    fn mul(self, rhs: Self) -> Self::Output {
-        let (l, r) = (self.len(), rhs.len());
-        if let (0, _) | (_, 0) = (l, r) {
-            return Self::ZERO;
-        }
-        if let (1, 1) = (l, r) {
-            let prod =
-                self.digits.first().unwrap() * rhs.digits.first().unwrap();
-            return BigInteger::from_parts(vec![prod % 10, prod / 10], 1);
-        }
+    fn add(self, rhs: &BigInteger) -> Self::Output {
+        self.clone() + rhs
+    }
+}
 
-        let n = max(l, r) + max(l, r) % 2;
-        let signum = self.signum * rhs.signum;
-        let (b, a) = self.abs().split_on(n / 2);
-        let (d, c) = rhs.abs().split_on(n / 2);
-        let ac = a.clone() * c.clone();
-        let bd = b.clone() * d.clone();
-        let adbc = (a + b) * (c + d) - ac.clone() - bd.clone();
+impl Sub<BigInteger> for &BigInteger {
+    type Output = BigInteger;
 
-        let mut r = (ac << n) + ((adbc) << (n / 2)) + bd;
-        r.signum = signum;
-        r
+    fn sub(self, rhs: BigInteger) -> Self::Output {
+        -(rhs - self)
     }
 }
 
-impl Shl<usize> for BigInteger {
-    type Output = Self;
+impl Sub<&BigInteger> for &BigInteger {
+    type Output = BigInteger;
 
-    fn shl(mut self, n: usize) -> Self::Output {
-        self.digits = lsh(self.digits, n);
-        self
+    fn sub(self, rhs: &BigInteger) -> Self::Output {
+        self.clone() - rhs
     }
 }
 
 impl PartialOrd for BigInteger {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(match (self.clone() - other.clone()).signum() {
-            1 => Ordering::Greater,
-            0 => Ordering::Equal,
-            -1 => Ordering::Less,
-            _ => unsafe { std::hint::unreachable_unchecked() },
-        })
+        Some(self.cmp(other))
     }
 }
 
 impl Ord for BigInteger {
     fn cmp(&self, other: &Self) -> Ordering {
-        unsafe { self.partial_cmp(other).unwrap_unchecked() }
+        let signum = self.signum();
+        signum.cmp(&other.signum()).then_with(|| match signum {
+            -1 => other.cmp_abs(self),
+            _ => self.cmp_abs(other),
+        })
     }
 }
 
-pub fn division(mut x: BigInteger, y: BigInteger) -> BigInteger {
-    assert!(x.signum() >= 0);
-    assert!(y.signum() > 0);
-    let n = y.len();
-    let m = x.len();
-    if n > m {
-        return BigInteger::ZERO;
+impl PartialEq for BigInteger {
+    fn eq(&self, other: &Self) -> bool {
+        self.signum() == other.signum() && self.digits[..] == other.digits[..]
     }
+}
 
-    let mut result = Vec::new();
-    for i in (0..=(m - n)).into_iter().rev() {
-        let z = y.clone() << i;
-        let mut c = 0;
-        while x >= z {
-            x = x - z.clone();
-            c += 1
-        }
-        result.push(c);
-        if x == BigInteger::ZERO {
-            break;
-        }
-    }
-    result.reverse();
+impl Eq for BigInteger {}
 
-    BigInteger::from_parts(result, 1)
+impl Hash for BigInteger {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.signum().hash(state);
+        self.digits[..].hash(state);
+    }
 }
 
+#[cfg(test)]
 macro_rules! big_int {
     ($value:literal) => {
         $value.parse::<BigInteger>().unwrap()
@@ -221,6 +278,18 @@ macro_rules! big_int {
 mod tests {
     use super::*;
 
+    fn random(seed: &mut u64, n: usize) -> BigInteger {
+        let digits = (0..n)
+            .map(|_| {
+                *seed ^= *seed << 13;
+                *seed ^= *seed >> 7;
+                *seed ^= *seed << 17;
+                *seed
+            })
+            .collect();
+        BigInteger::from_parts(digits, 1)
+    }
+
     #[test]
     fn add_sub() {
         assert_eq!(big_int!("-42") + big_int!("42"), BigInteger::ZERO);
@@ -233,6 +302,18 @@ mod tests {
         assert_eq!(big_int!("-42") * -big_int!("42"), big_int!("1764"));
         assert_eq!(big_int!("0") * big_int!("42"), big_int!("0"));
         assert_eq!(big_int!("42") * big_int!("1764"), big_int!("74088"));
+        assert_eq!(
+            big_int!("18446744073709551616")
+                * big_int!("-18446744073709551615"),
+            big_int!("-340282366920938463444927863358058659840")
+        );
+        assert_eq!(
+            big_int!("123456789012345678901234567890")
+                * big_int!("987654321098765432109876543210"),
+            big_int!(
+                "121932631137021795226185032733622923332237463801111263526900"
+            )
+        );
     }
 
     #[test]
@@ -241,5 +322,1180 @@ mod tests {
         assert_eq!(division(big_int!("10"), big_int!("2")), big_int!("5"));
         assert_eq!(division(big_int!("2"), big_int!("42")), big_int!("0"));
         assert_eq!(division(big_int!("121"), big_int!("11")), big_int!("11"));
+        assert_eq!(
+            division(
+                big_int!(
+                    "121932631137021795226185032733622923332237463801111263526900"
+                ),
+                big_int!("987654321098765432109876543210")
+            ),
+            big_int!("123456789012345678901234567890")
+        );
+    }
+
+    #[test]
+    fn mul_tiers() {
+        let tiers = [
+            (usize::MAX, usize::MAX, usize::MAX),
+            (4, usize::MAX, usize::MAX),
+            (4, 4, usize::MAX),
+            (4, 4, 4),
+            (8, 24, 100),
+        ]
+        .map(|(karatsuba, toom3, ntt)| MulThresholds {
+            karatsuba,
+            toom3,
+            ntt,
+        });
+        let mut seed = 0x9e3779b97f4a7c15_u64;
+        for (l, r) in [
+            (1, 1),
+            (5, 4),
+            (37, 36),
+            (61, 33),
+            (120, 70),
+            (120, 97),
+            (300, 7),
+            (250, 60),
+        ] {
+            let x = random(&mut seed, l);
+            let y = -random(&mut seed, r);
+            let expected = x.mul_with(&y, &tiers[0]);
+            for t in &tiers[1..] {
+                assert_eq!(x.mul_with(&y, t), expected, "{t:?} {l}x{r}");
+                assert_eq!(y.mul_with(&x, t), expected, "{t:?} {r}x{l}");
+            }
+        }
+
+        let x = BigInteger::from_parts(vec![Digit::MAX; 300], 1);
+        let expected = x.mul_with(&x, &tiers[0]);
+        for t in &tiers[1..] {
+            assert_eq!(x.mul_with(&x, t), expected);
+        }
+        assert_eq!(x.mul_with(&BigInteger::ZERO, &tiers[3]), BigInteger::ZERO);
+    }
+
+    #[test]
+    fn long_div() {
+        let mut seed = 0x2545f4914f6cdd1d_u64;
+        for (l, r) in [(3, 2), (10, 4), (90, 40), (200, 70), (300, 150)] {
+            let (x, y) = (random(&mut seed, l), random(&mut seed, r));
+            let (q, rem) = x.div_rem(&y);
+            assert!(rem.signum() >= 0 && rem < y);
+            assert_eq!(q * y + rem, x);
+        }
+
+        let x = BigInteger::from_parts(vec![Digit::MAX; 200], 1);
+        let y = BigInteger::from_parts(vec![Digit::MAX; 70], 1);
+        let (q, rem) = x.div_rem(&y);
+        assert!(rem.signum() >= 0 && rem < y);
+        assert_eq!(q * y + rem, x);
+
+        let x = big_int!("340282366920938463463374607431768211455");
+        let y = big_int!("18446744073709551617");
+        assert_eq!(
+            x.div_rem(&y),
+            (big_int!("18446744073709551615"), big_int!("0"))
+        );
+    }
+
+    #[test]
+    fn signed_div() {
+        let cases = [
+            ("7", "3", "2", "1", "2", "1", "2", "1"),
+            ("-7", "3", "-2", "-1", "-3", "2", "-3", "2"),
+            ("7", "-3", "-2", "1", "-2", "1", "-3", "-2"),
+            ("-7", "-3", "2", "-1", "3", "2", "2", "-1"),
+            ("-6", "3", "-2", "0", "-2", "0", "-2", "0"),
+        ];
+        let parse = |s: &str| s.parse::<BigInteger>().unwrap();
+        for (x, y, q, r, qe, re, qf, rf) in cases {
+            let (x, y) = (parse(x), parse(y));
+            assert_eq!(x.clone() / y.clone(), parse(q));
+            assert_eq!(x.clone() % y.clone(), parse(r));
+            assert_eq!(x.div_rem_euclid(&y), (parse(qe), parse(re)));
+            assert_eq!(x.div_mod_floor(&y), (parse(qf), parse(rf)));
+        }
+
+        let mut x = big_int!("-100000000000000000000000000000");
+        x /= big_int!("7");
+        assert_eq!(x, big_int!("-14285714285714285714285714285"));
+        x %= big_int!("-1000");
+        assert_eq!(x, big_int!("-285"));
+    }
+
+    #[test]
+    fn div_by_zero() {
+        assert_eq!(big_int!("42").checked_div_rem(&BigInteger::ZERO), None);
+        let r = std::panic::catch_unwind(|| big_int!("42") / BigInteger::ZERO);
+        assert!(r.is_err());
+    }
+
+    #[test]
+    fn format() {
+        let x = big_int!("-340282366920938463463374607431768211455");
+        assert_eq!(x.to_string(), "-340282366920938463463374607431768211455");
+        assert_eq!(format!("{:x}", -x.clone()), "f".repeat(32));
+        assert_eq!(format!("{:#X}", x), format!("-0x{}", "F".repeat(32)));
+        assert_eq!(format!("{:#o}", big_int!("8")), "0o10");
+        assert_eq!(format!("{:b}", big_int!("-5")), "-101");
+        assert_eq!(format!("{:+}", big_int!("42")), "+42");
+        assert_eq!(format!("{:06}", big_int!("-42")), "-00042");
+        assert_eq!(format!("{:*^7}", big_int!("42")), "**42***");
+        assert_eq!(format!("{:>4}", BigInteger::ZERO), "   0");
+        assert_eq!(
+            format!("{:?}", big_int!("18446744073709551616")),
+            "18446744073709551616"
+        );
+        assert_eq!(big_int!("-1295").to_str_radix(36), "-zz");
+        assert_eq!(big_int!("35").to_str_radix(36), "z");
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(big_int!("+1_000_000"), big_int!("1000000"));
+        assert_eq!(big_int!("-0xff"), big_int!("-255"));
+        assert_eq!(big_int!("0o777"), big_int!("511"));
+        assert_eq!(big_int!("0b1010_1010"), big_int!("170"));
+        assert_eq!(big_int!("-0"), BigInteger::ZERO);
+        assert_eq!(BigInteger::from_str_radix("zz", 36), Ok(big_int!("1295")));
+        assert_eq!(
+            BigInteger::from_str_radix("0xFFFFFFFFFFFFFFFFFFFF", 16),
+            Ok(big_int!("1208925819614629174706175"))
+        );
+        assert_eq!(BigInteger::from_str_radix("0b1", 16), Ok(big_int!("177")));
+
+        use ParseBigIntError::*;
+        let err = |s: &str| s.parse::<BigInteger>().unwrap_err();
+        assert_eq!(err(""), Empty);
+        assert_eq!(err("-"), Empty);
+        assert_eq!(err("0x"), Empty);
+        assert_eq!(err("12-3"), MisplacedSign { offset: 2 });
+        assert_eq!(err("--1"), MisplacedSign { offset: 1 });
+        assert_eq!(err("1_2__3"), MisplacedSeparator { offset: 4 });
+        assert_eq!(err("_1"), MisplacedSeparator { offset: 0 });
+        assert_eq!(err("1_"), MisplacedSeparator { offset: 1 });
+        assert_eq!(err("1é2"), InvalidDigit { digit: 'é', offset: 1 });
+        assert_eq!(err("0xfg"), InvalidDigit { digit: 'g', offset: 3 });
+        assert_eq!(BigInteger::from_str_radix("1", 37), Err(InvalidRadix(37)));
+        assert_eq!(err("12a").offset(), Some(2));
+        assert_eq!(err("12a").to_string(), "invalid digit 'a' at offset 2");
+    }
+
+    #[test]
+    fn borrowed_ops() {
+        let (x, y) = (big_int!("-123456789012345678901234"), big_int!("987"));
+        assert_eq!(&x + &y, x.clone() + y.clone());
+        assert_eq!(&x - y.clone(), x.clone() - y.clone());
+        assert_eq!(x.clone() * &y, big_int!("-121851850755185185075517958"));
+        assert_eq!(&x / &y, big_int!("-125082866273906462919"));
+        assert_eq!(&x % y.clone(), big_int!("-181"));
+
+        let mut z = x.clone();
+        z += &y;
+        z -= y.clone();
+        assert_eq!(z, x);
+        z *= &y;
+        z /= &y;
+        assert_eq!(z, x);
+        z -= &x;
+        assert_eq!(z, BigInteger::ZERO);
+        z -= &y;
+        assert_eq!(z, -y.clone());
+    }
+
+    #[test]
+    fn primitive_ops() {
+        let x = big_int!("18446744073709551615");
+        assert_eq!(&x + 1_u32, big_int!("18446744073709551616"));
+        assert_eq!(x.clone() - -1_i8, big_int!("18446744073709551616"));
+        assert_eq!(5_u64 - x.clone(), big_int!("-18446744073709551610"));
+        assert_eq!(-3_i64 * x.clone(), big_int!("-55340232221128654845"));
+        assert_eq!(x.clone() / -10_isize, big_int!("-1844674407370955161"));
+        assert_eq!(-x.clone() % 10_usize, big_int!("-5"));
+        assert_eq!(x.clone() * std::hint::black_box(0_u8), BigInteger::ZERO);
+
+        let mut y = BigInteger::ZERO;
+        y += 7_u16;
+        y -= 10_i32;
+        assert_eq!(y, big_int!("-3"));
+        y *= -2_i16;
+        y %= 4_u8;
+        assert_eq!(y, big_int!("2"));
+        y /= 3_u64;
+        assert_eq!(y, BigInteger::ZERO);
+
+        assert_eq!(5_u32 + &x, big_int!("18446744073709551620"));
+        assert_eq!(5_u8 - &x, big_int!("-18446744073709551610"));
+        assert_eq!(-2_i8 * &x, big_int!("-36893488147419103230"));
+        assert_eq!(100_u8 / big_int!("-7"), big_int!("-14"));
+        assert_eq!(-100_i32 % &big_int!("7"), big_int!("-2"));
+        assert_eq!(u64::MAX / &x, big_int!("1"));
+
+        let wide = u128::MAX;
+        let big_wide = BigInteger::from(wide);
+        assert_eq!(&x + wide, &x + &big_wide);
+        assert_eq!(&x - i128::MIN, &x + (big_int!("1") << 127));
+        assert_eq!(&x * wide, &x * &big_wide);
+        assert_eq!(&big_wide * &x / wide, x);
+        assert_eq!(-(&big_wide * &x) % -3_i128, big_int!("0"));
+        assert_eq!((&big_wide * 5_u8 + 4_u8) % wide, big_int!("4"));
+        assert_eq!(wide - &big_wide, BigInteger::ZERO);
+
+        let mut z = big_int!("1") << 200;
+        let digits = z.digits.as_ptr();
+        z *= 3_u8;
+        assert_eq!(z, big_int!("3") << 200);
+        assert_eq!(z.digits.as_ptr(), digits);
+        z *= -0_i64;
+        assert_eq!(z, BigInteger::ZERO);
+    }
+
+    #[test]
+    fn primitive_convert() {
+        assert_eq!(
+            BigInteger::from(i128::MIN).to_string(),
+            i128::MIN.to_string()
+        );
+        assert_eq!(
+            BigInteger::from(u128::MAX).to_string(),
+            u128::MAX.to_string()
+        );
+        assert_eq!(BigInteger::from(-42_i8), big_int!("-42"));
+        assert_eq!(BigInteger::from(0_usize), BigInteger::ZERO);
+        assert_eq!(BigInteger::from(true), big_int!("1"));
+        assert_eq!(BigInteger::from(false), BigInteger::ZERO);
+
+        assert_eq!(i128::try_from(BigInteger::from(i128::MIN)), Ok(i128::MIN));
+        assert_eq!(i128::try_from(BigInteger::from(i128::MAX)), Ok(i128::MAX));
+        assert_eq!(u128::try_from(BigInteger::from(u128::MAX)), Ok(u128::MAX));
+        assert_eq!(i8::try_from(&big_int!("-128")), Ok(-128));
+        assert!(i8::try_from(&big_int!("128")).is_err());
+        assert!(i8::try_from(&big_int!("-129")).is_err());
+        assert!(u32::try_from(&big_int!("-1")).is_err());
+        assert!(u128::try_from(&big_int!(
+            "340282366920938463463374607431768211456"
+        ))
+        .is_err());
+        assert_eq!(u64::try_from(&BigInteger::ZERO), Ok(0));
+    }
+
+    #[test]
+    fn float_convert() {
+        assert_eq!(big_int!("0").to_f64(), 0.0);
+        assert_eq!(big_int!("-12345").to_f64(), -12345.0);
+        assert_eq!(
+            big_int!("18446744073709551615").to_f64(),
+            18446744073709551615.0
+        );
+        assert_eq!(big_int!("9007199254740993").to_f64(), 9007199254740992.0);
+        assert_eq!(
+            big_int!("340282366920938463463374607431768211455").to_f64(),
+            340282366920938463463374607431768211455.0
+        );
+        let x = big_int!("9007199254740993") * (1_u64 << 30);
+        assert_eq!(x.to_f64(), 9007199254740992.0 * 2_f64.powi(30));
+        assert_eq!((x + 1_u8).to_f64(), 9007199254740994.0 * 2_f64.powi(30));
+        let max = BigInteger::from_f64(f64::MAX).unwrap();
+        assert_eq!(max.to_f64(), f64::MAX);
+        assert_eq!((-max * 2_u8).to_f64(), f64::NEG_INFINITY);
+
+        assert_eq!(BigInteger::from_f64(-2.9), Some(big_int!("-2")));
+        assert_eq!(BigInteger::from_f64(0.5), Some(BigInteger::ZERO));
+        assert_eq!(
+            BigInteger::from_f64(1e20),
+            Some(big_int!("100000000000000000000"))
+        );
+        assert_eq!(BigInteger::from_f64(f64::NAN), None);
+        assert_eq!(BigInteger::from_f64(f64::NEG_INFINITY), None);
+    }
+
+    #[test]
+    fn ord() {
+        let mut xs = [
+            "3",
+            "-18446744073709551616",
+            "0",
+            "-2",
+            "18446744073709551616",
+            "-0",
+            "-3",
+        ]
+        .map(|s| s.parse::<BigInteger>().unwrap());
+        xs.sort();
+        assert_eq!(
+            xs.map(|x| x.to_string()),
+            [
+                "-18446744073709551616",
+                "-3",
+                "-2",
+                "0",
+                "0",
+                "3",
+                "18446744073709551616"
+            ]
+        );
+        assert!(big_int!("-5") < big_int!("-4"));
+        assert_eq!(big_int!("-5").cmp_abs(&big_int!("4")), Ordering::Greater);
+        assert_eq!(big_int!("-7").cmp_abs(&big_int!("7")), Ordering::Equal);
+    }
+
+    #[test]
+    fn hash() {
+        use std::collections::hash_map::DefaultHasher;
+
+        let hash = |x: &BigInteger| {
+            let mut hasher = DefaultHasher::new();
+            x.hash(&mut hasher);
+            hasher.finish()
+        };
+        let zeros = [
+            BigInteger::ZERO,
+            -BigInteger::ZERO,
+            big_int!("-0"),
+            big_int!("5") - big_int!("5"),
+            big_int!("-18446744073709551616") * BigInteger::ZERO,
+            BigInteger { digits: Limbs::new(), signum: -1 },
+        ];
+        for zero in &zeros {
+            assert_eq!(*zero, BigInteger::ZERO);
+            assert_eq!(hash(zero), hash(&BigInteger::ZERO));
+        }
+        assert_eq!(hash(&big_int!("-42")), hash(&-big_int!("42")));
+    }
+
+    #[test]
+    fn bitwise() {
+        for x in
+            [-300_i128, -129, -64, -1, 0, 1, 5, 255, 1 << 70, -(1 << 90) + 3]
+        {
+            for y in [-7_i128, -1, 0, 3, 1 << 64, -(1 << 65)] {
+                let (bx, by) = (BigInteger::from(x), BigInteger::from(y));
+                assert_eq!(&bx & &by, BigInteger::from(x & y));
+                assert_eq!(&bx | &by, BigInteger::from(x | y));
+                assert_eq!(&bx ^ &by, BigInteger::from(x ^ y));
+            }
+            let bx = BigInteger::from(x);
+            assert_eq!(!&bx, BigInteger::from(!x));
+            for n in [0, 1, 3, 63, 64, 65, 100] {
+                assert_eq!(&bx >> n, BigInteger::from(x >> n.min(127)));
+                assert_eq!(bx.bit(n as u64), x >> n.min(127) & 1 == 1);
+            }
+            assert_eq!(&bx << 37, BigInteger::from(x << 37));
+        }
+
+        let mut x = big_int!("-1");
+        x &= big_int!("255");
+        x |= big_int!("256");
+        x ^= big_int!("1");
+        assert_eq!(x, big_int!("510"));
+        x <<= 100;
+        x >>= 99;
+        assert_eq!(x, big_int!("1020"));
+    }
+
+    #[test]
+    fn bit_queries() {
+        assert_eq!(BigInteger::ZERO.bit_length(), 0);
+        assert_eq!(big_int!("-255").bit_length(), 8);
+        assert_eq!(big_int!("18446744073709551616").bit_length(), 65);
+        assert_eq!(big_int!("-255").count_ones(), 8);
+        assert_eq!(BigInteger::ZERO.trailing_zeros(), None);
+        assert_eq!(
+            big_int!("-36893488147419103232").trailing_zeros(),
+            Some(65)
+        );
+
+        let mut x = BigInteger::ZERO;
+        x.set_bit(100, true);
+        assert_eq!(x, big_int!("1") << 100);
+        x.set_bit(100, false);
+        assert_eq!(x, BigInteger::ZERO);
+
+        let mut y = big_int!("-1");
+        y.set_bit(3, false);
+        assert_eq!(y, big_int!("-9"));
+        y.set_bit(70, false);
+        assert_eq!(y, BigInteger::from(-9_i128 & !(1 << 70)));
+        y.set_bit(70, true);
+        assert_eq!(y, big_int!("-9"));
+        assert!(y.bit(1000));
+        assert!(!y.bit(3));
+    }
+
+    #[test]
+    fn gcd() {
+        assert_eq!(big_int!("0").gcd(&big_int!("0")), big_int!("0"));
+        assert_eq!(big_int!("0").gcd(&big_int!("-12")), big_int!("12"));
+        assert_eq!(big_int!("-48").gcd(&big_int!("18")), big_int!("6"));
+        assert_eq!(big_int!("17").gcd(&big_int!("1")), big_int!("1"));
+        let x = big_int!("1") << 200;
+        let y = BigInteger::from(3_u8 << 5)
+            * big_int!("340282366920938463463374607431768211297");
+        assert_eq!(x.gcd(&y), big_int!("32"));
+        assert_eq!(y.gcd(&(&y * 7_u8)), y);
+        assert_eq!(big_int!("-4").lcm(&big_int!("6")), big_int!("12"));
+        assert_eq!(big_int!("4").lcm(&BigInteger::ZERO), BigInteger::ZERO);
+    }
+
+    #[test]
+    fn extended_gcd() {
+        for (a, b) in [
+            ("240", "46"),
+            ("-240", "46"),
+            ("0", "-5"),
+            ("7", "0"),
+            ("12345678901234567890123", "-9876543210987"),
+        ] {
+            let (a, b) = (a.parse::<BigInteger>().unwrap(), b.parse().unwrap());
+            let (g, x, y) = a.extended_gcd(&b);
+            assert_eq!(g, a.gcd(&b));
+            assert_eq!(&a * &x + &b * &y, g);
+        }
+
+        assert_eq!(
+            big_int!("3").mod_inverse(&big_int!("11")),
+            Some(big_int!("4"))
+        );
+        assert_eq!(
+            big_int!("-3").mod_inverse(&big_int!("11")),
+            Some(big_int!("7"))
+        );
+        assert_eq!(big_int!("6").mod_inverse(&big_int!("9")), None);
+        assert_eq!(big_int!("6").mod_inverse(&BigInteger::ZERO), None);
+        assert_eq!(
+            big_int!("5").mod_inverse(&big_int!("1")),
+            Some(BigInteger::ZERO)
+        );
+    }
+
+    #[test]
+    fn crt() {
+        let pairs = |xs: &[(i32, i32)]| -> Vec<_> {
+            xs.iter().map(|&(r, m)| (r.into(), m.into())).collect()
+        };
+        let solve = |xs: &[(i32, i32)]| BigInteger::crt(&pairs(xs));
+        assert_eq!(solve(&[(2, 3), (3, 5), (2, 7)]), Ok(big_int!("23")));
+        assert_eq!(solve(&[(-1, 4), (5, -6)]), Ok(big_int!("11")));
+        assert_eq!(solve(&[(1, 4), (2, 6)]), Err(CrtError::Inconsistent));
+        assert_eq!(
+            solve(&[(1, 4), (0, 0)]),
+            Err(CrtError::ZeroModulus { index: 1 })
+        );
+        assert_eq!(solve(&[]), Ok(BigInteger::ZERO));
+        let symmetric =
+            |xs: &[(i32, i32)]| BigInteger::crt_symmetric(&pairs(xs));
+        assert_eq!(symmetric(&[(2, 3), (3, 5), (2, 7)]), Ok(big_int!("23")));
+        assert_eq!(symmetric(&[(2, 3), (4, 5), (6, 7)]), Ok(big_int!("-1")));
+        assert_eq!(symmetric(&[(1, 2), (0, 3)]), Ok(big_int!("3")));
+        assert_eq!(
+            symmetric(&[(0, 0), (1, 4)]),
+            Err(CrtError::ZeroModulus { index: 0 })
+        );
+
+        let mut seed = 0x853c49e6748fea9b_u64;
+        let x = -random(&mut seed, 12);
+        let moduli: Vec<_> = (0..40).map(|_| random(&mut seed, 1)).collect();
+        let m = moduli.iter().fold(big_int!("1"), |acc, n| acc.lcm(n));
+        let congruences: Vec<_> =
+            moduli.into_iter().map(|n| (x.rem_euclid(&n), n)).collect();
+        assert_eq!(BigInteger::crt(&congruences), Ok(x.rem_euclid(&m)));
+        assert_eq!(BigInteger::crt_symmetric(&congruences), Ok(x));
+    }
+
+    #[test]
+    fn mod_pow() {
+        assert_eq!(
+            big_int!("4").mod_pow(&big_int!("13"), &big_int!("497")),
+            big_int!("445")
+        );
+        assert_eq!(
+            big_int!("-2").mod_pow(&big_int!("3"), &big_int!("5")),
+            big_int!("2")
+        );
+        assert_eq!(
+            big_int!("7").mod_pow(&BigInteger::ZERO, &big_int!("1")),
+            BigInteger::ZERO
+        );
+        assert_eq!(
+            big_int!("7").mod_pow(&BigInteger::ZERO, &big_int!("13")),
+            big_int!("1")
+        );
+
+        let p = big_int!("170141183460469231731687303715884105727");
+        let a = big_int!("123456789123456789123456789");
+        assert_eq!(a.mod_pow(&(&p - 1_u8), &p), big_int!("1"));
+        let e = (big_int!("1") << 1000) + 12345_u32;
+        let m = (big_int!("1") << 300) - 153_u8;
+        let mut expected = big_int!("1");
+        let mut base = a.clone();
+        for i in 0..e.bit_length() {
+            if e.bit(i) {
+                expected = expected * &base % &m;
+            }
+            base = &base * &base % &m;
+        }
+        assert_eq!(a.mod_pow(&e, &m), expected);
+    }
+
+    #[test]
+    fn modular_contexts() {
+        let mut seed = 7;
+        for (n, odd) in
+            [(1, true), (3, true), (40, true), (1, false), (5, false)]
+        {
+            let mut m = random(&mut seed, n);
+            m.set_bit(0, odd);
+            let barrett = BarrettContext::new(&m);
+            let x = -random(&mut seed, 2 * n);
+            let y = random(&mut seed, n) % &m;
+            let e = random(&mut seed, 2);
+            let expected = x.mod_pow(&e, &m);
+            assert_eq!(barrett.pow(&x, &e), expected);
+            let xr = x.rem_euclid(&m);
+            assert_eq!(barrett.reduce(&(&xr * &y)), &xr * &y % &m);
+            assert_eq!(barrett.square(&y), &y * &y % &m);
+            if !odd {
+                continue;
+            }
+            let ctx = MontgomeryContext::new(&m);
+            let (a, b) = (ctx.to_montgomery(&x), ctx.to_montgomery(&y));
+            assert_eq!(ctx.from_montgomery(&a), xr);
+            assert_eq!(ctx.from_montgomery(&ctx.mul(&a, &b)), &xr * &y % &m);
+            assert_eq!(ctx.square(&a), ctx.mul(&a, &a));
+            assert_eq!(ctx.from_montgomery(&ctx.pow(&a, &e)), expected);
+            assert_eq!(ctx.from_montgomery(ctx.one()), big_int!("1"));
+        }
+    }
+
+    #[test]
+    fn rational() {
+        let q = |s: &str| s.parse::<BigRational>().unwrap();
+        let half = q("1/2");
+        assert_eq!(q("6/-4"), q("-3/2"));
+        assert_eq!(q("-6/4").num(), &big_int!("-3"));
+        assert_eq!(q("-6/4").den(), &big_int!("2"));
+        assert_eq!(q("1.25"), q("5/4"));
+        assert_eq!(q("-0.05"), q("-1/20"));
+        assert_eq!(q("0/7"), BigRational::default());
+        assert_eq!(&half + &q("1/3"), q("5/6"));
+        assert_eq!(&half - q("1/3"), q("1/6"));
+        assert_eq!(q("2/3") * q("9/4"), q("3/2"));
+        assert_eq!(q("2/3") / q("-4/9"), q("-3/2"));
+        assert_eq!(-half.clone(), q("-1/2"));
+        let mut x = q("1/3");
+        x += &half;
+        x *= q("6");
+        assert_eq!(x, q("5"));
+        assert!(x.is_integer());
+        assert!(q("-1/2") < q("-1/3") && q("1/3") < half);
+        assert_eq!(q("2/3").recip(), q("3/2"));
+
+        assert_eq!(
+            "1/0".parse::<BigRational>(),
+            Err(ParseRationalError::ZeroDenominator)
+        );
+        assert_eq!(
+            "12/3x".parse::<BigRational>(),
+            Err(ParseRationalError::Integer(ParseBigIntError::InvalidDigit {
+                digit: 'x',
+                offset: 4
+            }))
+        );
+        assert_eq!(
+            "1.2-".parse::<BigRational>().unwrap_err(),
+            ParseRationalError::Integer(ParseBigIntError::MisplacedSign {
+                offset: 3
+            })
+        );
+        assert_eq!(q("-1_0.2_5"), q("-41/4"));
+        assert_eq!(
+            "1._5".parse::<BigRational>(),
+            Err(ParseRationalError::Integer(
+                ParseBigIntError::MisplacedSeparator { offset: 2 }
+            ))
+        );
+        assert_eq!(
+            "1_.5".parse::<BigRational>(),
+            Err(ParseRationalError::Integer(
+                ParseBigIntError::MisplacedSeparator { offset: 1 }
+            ))
+        );
+
+        for (s, floor, ceil, round) in [
+            ("7/2", "3", "4", "4"),
+            ("-7/2", "-4", "-3", "-4"),
+            ("-5/3", "-2", "-1", "-2"),
+            ("4", "4", "4", "4"),
+        ] {
+            assert_eq!(q(s).floor(), floor.parse::<BigInteger>().unwrap());
+            assert_eq!(q(s).ceil(), ceil.parse::<BigInteger>().unwrap());
+            assert_eq!(q(s).round(), round.parse::<BigInteger>().unwrap());
+        }
+        assert_eq!(q("-7/2").trunc(), big_int!("-3"));
+
+        assert_eq!(q("1/3").to_f64(), 1.0 / 3.0);
+        assert_eq!(q("-5/4").to_f64(), -1.25);
+        let huge = BigRational::from(big_int!("1") << 1100);
+        assert_eq!(huge.to_f64(), f64::INFINITY);
+        let tiny = BigRational::new(big_int!("1"), big_int!("1") << 2000);
+        assert_eq!(tiny.to_f64(), 0.0);
+        let big = BigRational::new(big_int!("1") << 1000, big_int!("3"));
+        assert_eq!(big.to_f64(), 2_f64.powi(1000) / 3.0);
+
+        assert_eq!(q("-6/4").to_string(), "-3/2");
+        assert_eq!(q("5").to_string(), "5");
+        assert_eq!(format!("{:.3}", q("2/3")), "0.667");
+        assert_eq!(format!("{:.2}", q("-1/8")), "-0.13");
+        assert_eq!(format!("{:.2}", q("-1/1000")), "0.00");
+        assert_eq!(format!("{:.0}", q("5/2")), "3");
+        assert_eq!(format!("{:>8.1}", q("-1/4")), "    -0.3");
+    }
+
+    #[test]
+    fn decimal() {
+        use RoundingMode::*;
+        let d = |s: &str| s.parse::<BigDecimal>().unwrap();
+        assert_eq!(d("1.50"), d("1.5"));
+        assert_eq!(d("1.50").scale(), 2);
+        assert_eq!(d("-6.02e23"), BigDecimal::new(big_int!("-602"), -21));
+        assert_eq!(d("1E-3").to_string(), "0.001");
+        assert_eq!(d(".5").to_string(), "0.5");
+        assert_eq!(d("12e2").to_string(), "1200");
+        assert_eq!(d("0e5").to_string(), "0");
+        assert_eq!(d("-0.000123").precision(), 3);
+        assert_eq!(
+            "1.5e".parse::<BigDecimal>(),
+            Err(ParseDecimalError::InvalidExponent { offset: 4 })
+        );
+        assert_eq!(d("-1_0.5_0"), BigDecimal::new(big_int!("-1050"), 2));
+        assert_eq!(d("-1_0.5_0").to_string(), "-10.50");
+        assert_eq!(
+            "1._5".parse::<BigDecimal>(),
+            Err(ParseDecimalError::Integer(
+                ParseBigIntError::MisplacedSeparator { offset: 2 }
+            ))
+        );
+        assert_eq!(
+            "1.x".parse::<BigDecimal>(),
+            Err(ParseDecimalError::Integer(ParseBigIntError::InvalidDigit {
+                digit: 'x',
+                offset: 2
+            }))
+        );
+
+        assert_eq!(d("0.1") + d("0.25"), d("0.35"));
+        assert_eq!((d("1.10") - d("0.1")).to_string(), "1.00");
+        assert_eq!((d("1.5") * d("-0.20")).to_string(), "-0.300");
+        assert!(d("-1.5") < d("-1.25") && d("0.3") > d("0.25"));
+        let mut set = std::collections::HashSet::new();
+        set.insert(d("2.50"));
+        assert!(set.contains(&d("2.5")));
+
+        let one = d("1");
+        let far = BigDecimal::new(big_int!("1"), -(1 << 32));
+        assert!(far != one && far > one && -&far < -&one);
+        assert!(BigDecimal::new(big_int!("1"), 1 << 32) < one);
+        assert_eq!(
+            BigDecimal::new(big_int!("10"), 1 << 40),
+            BigDecimal::new(big_int!("1"), (1 << 40) - 1)
+        );
+        assert_eq!(BigDecimal::new(BigInteger::ZERO, 1 << 40), d("0"));
+        assert!(d("1e2147483647") > d("1e-2147483647"));
+        assert_eq!(
+            "1e4294967296".parse::<BigDecimal>(),
+            Err(ParseDecimalError::InvalidExponent { offset: 2 })
+        );
+        assert!(std::panic::catch_unwind(|| &far + &one).is_err());
+
+        let cases = [
+            ("2.5", ["2", "3", "2", "3", "2"]),
+            ("3.5", ["4", "4", "3", "4", "3"]),
+            ("-2.5", ["-2", "-3", "-3", "-2", "-2"]),
+            ("-2.51", ["-3", "-3", "-3", "-2", "-2"]),
+            ("2.49", ["2", "2", "2", "3", "2"]),
+        ];
+        for (s, expected) in cases {
+            let modes = [HalfEven, HalfUp, Floor, Ceiling, Truncate];
+            for (mode, e) in modes.into_iter().zip(expected) {
+                assert_eq!(d(s).with_scale(0, mode), d(e), "{s} {mode:?}");
+            }
+        }
+        assert_eq!(d("9.996").round(3, HalfEven).to_string(), "10.0");
+        assert_eq!(d("123456").round(2, HalfUp).to_string(), "120000");
+        assert_eq!(format!("{:e}", d("123456").round(2, HalfUp)), "1.2e5");
+
+        let third = d("1").div_to_precision(&d("3"), 20, HalfEven);
+        assert_eq!(third.to_string(), "0.33333333333333333333");
+        let two_thirds = d("-2").div_to_precision(&d("3"), 5, HalfEven);
+        assert_eq!(two_thirds.to_string(), "-0.66667");
+        let two_thirds = d("-2").div_to_precision(&d("3"), 5, Truncate);
+        assert_eq!(two_thirds.to_string(), "-0.66666");
+        assert_eq!((d("10.00") / d("4")).to_string(), "2.50");
+        assert_eq!((d("1") / d("8")).to_string(), "0.125");
+        assert_eq!((d("1e10") / d("-2.5e-3")).to_string(), "-4000000000000");
+
+        assert_eq!(d("2").sqrt(10, HalfEven).to_string(), "1.414213562");
+        assert_eq!(d("2").sqrt(4, Ceiling).to_string(), "1.415");
+        assert_eq!(d("0.0144").sqrt(10, HalfEven).to_string(), "0.12");
+        assert_eq!(d("1e6").sqrt(10, HalfEven).to_string(), "1000");
+        assert_eq!(d("6.25").sqrt(1, HalfEven).to_string(), "2");
+        assert_eq!(d("6.25").sqrt(1, HalfUp).to_string(), "3");
+
+        assert_eq!(d("-1234.5").to_string(), "-1234.5");
+        assert_eq!(format!("{:.2}", d("2.675")), "2.68");
+        assert_eq!(format!("{:.1}", d("-0.04")), "0.0");
+        assert_eq!(format!("{:>8}", d("-0.5")), "    -0.5");
+        assert_eq!(format!("{:e}", d("-1234.5")), "-1.2345e3");
+        assert_eq!(format!("{:E}", d("0.00120")), "1.20E-3");
+        assert_eq!(format!("{:e}", d("7")), "7e0");
+        assert_eq!(format!("{:.2e}", d("-1234.5")), "-1.23e3");
+        assert_eq!(format!("{:.1E}", d("0.00996")), "1.0E-2");
+        assert_eq!(format!("{:.3e}", d("7")), "7.000e0");
+        assert_eq!(format!("{:.0e}", d("25")), "2e1");
+    }
+
+    #[test]
+    fn bytes() {
+        let x = big_int!("-74565");
+        assert_eq!(x.to_bytes_be(), (-1, vec![0x01, 0x23, 0x45]));
+        assert_eq!(x.to_bytes_le(), (-1, vec![0x45, 0x23, 0x01]));
+        assert_eq!(BigInteger::ZERO.to_bytes_be(), (0, vec![0]));
+        assert_eq!(BigInteger::from_bytes_be(-1, &[1, 0x23, 0x45]), x);
+        assert_eq!(BigInteger::from_bytes_le(0, &[7]), BigInteger::ZERO);
+        assert_eq!(BigInteger::from_bytes_le(1, &[0, 0]), BigInteger::ZERO);
+
+        for (s, be) in [
+            ("0", &[0x00][..]),
+            ("127", &[0x7f]),
+            ("128", &[0x00, 0x80]),
+            ("-128", &[0x80]),
+            ("-129", &[0xff, 0x7f]),
+            ("-256", &[0xff, 0x00]),
+            ("-1", &[0xff]),
+        ] {
+            let x: BigInteger = s.parse().unwrap();
+            assert_eq!(x.to_signed_bytes_be(), be, "{s}");
+            assert_eq!(BigInteger::from_signed_bytes_be(be), x);
+        }
+        assert_eq!(BigInteger::from_signed_bytes_le(&[]), BigInteger::ZERO);
+
+        assert_eq!(big_int!("0").to_varint(), [0x00]);
+        assert_eq!(big_int!("-1").to_varint(), [0x01]);
+        assert_eq!(big_int!("63").to_varint(), [0x7e]);
+        assert_eq!(big_int!("-64").to_varint(), [0x7f]);
+        assert_eq!(big_int!("64").to_varint(), [0x80, 0x01]);
+        assert_eq!(big_int!("150").to_varint(), [0xac, 0x02]);
+        assert_eq!(BigInteger::from_varint(&[0x80, 0x80]), None);
+        assert_eq!(
+            BigInteger::from_varint(&[0xac, 0x02, 0xff]),
+            Some((big_int!("150"), 2))
+        );
+
+        let mut seed = 5;
+        for n in [1, 2, 7, 30] {
+            for x in [random(&mut seed, n), -random(&mut seed, n)] {
+                let (signum, be) = x.to_bytes_be();
+                assert_eq!(BigInteger::from_bytes_be(signum, &be), x);
+                let le = x.to_signed_bytes_le();
+                assert_eq!(BigInteger::from_signed_bytes_le(&le), x);
+                let v = x.to_varint();
+                assert_eq!(BigInteger::from_varint(&v), Some((x, v.len())));
+            }
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        use serde_test::{assert_de_tokens, assert_tokens, Configure, Token};
+        let x = big_int!("-129");
+        assert_tokens(&x.clone().readable(), &[Token::Str("-129")]);
+        assert_tokens(&x.clone().compact(), &[Token::Bytes(&[0x7f, 0xff])]);
+        assert_de_tokens(&x.readable(), &[Token::I64(-129)]);
+    }
+
+    #[test]
+    fn combinatorics() {
+        let naive = |xs: &mut dyn Iterator<Item = u64>| {
+            xs.fold(big_int!("1"), |acc, x| acc * x)
+        };
+        assert_eq!(BigInteger::factorial(0), big_int!("1"));
+        assert_eq!(
+            BigInteger::factorial(25),
+            big_int!("15511210043330985984000000")
+        );
+        assert_eq!(BigInteger::factorial(300), naive(&mut (1..=300)));
+        assert_eq!(BigInteger::double_factorial(0), big_int!("1"));
+        assert_eq!(BigInteger::double_factorial(9), big_int!("945"));
+        assert_eq!(BigInteger::double_factorial(10), big_int!("3840"));
+        assert_eq!(
+            BigInteger::double_factorial(301),
+            naive(&mut (1..=301).step_by(2))
+        );
+
+        assert_eq!(BigInteger::binomial(5, 7), BigInteger::ZERO);
+        assert_eq!(BigInteger::binomial(10, 0), big_int!("1"));
+        assert_eq!(BigInteger::binomial(52, 5), big_int!("2598960"));
+        assert_eq!(
+            BigInteger::binomial(100, 50),
+            big_int!("100891344545564193334812497256")
+        );
+        let n = 1_000_000_000_000_u64;
+        assert_eq!(
+            BigInteger::binomial(n, 2),
+            BigInteger::from(n) * (n - 1) / 2_u8
+        );
+
+        let (mut f, mut g) = (BigInteger::ZERO, big_int!("1"));
+        for n in 0..200 {
+            assert_eq!(BigInteger::fibonacci(n), f);
+            assert_eq!(BigInteger::lucas(n), &g * 2_u8 - &f);
+            (f, g) = (g.clone(), f + g);
+        }
+        assert_eq!(BigInteger::lucas(0), big_int!("2"));
+        assert_eq!(BigInteger::lucas(10), big_int!("123"));
+
+        assert_eq!(BigInteger::primorial(1), big_int!("1"));
+        assert_eq!(BigInteger::primorial(30), big_int!("6469693230"));
+        let primes = (2..=500).filter(|&p| (2..p).all(|d| p % d != 0));
+        assert_eq!(BigInteger::primorial(500), naive(&mut primes.into_iter()));
+    }
+
+    #[test]
+    fn sum_product() {
+        let xs: Vec<_> = (1..=30_u32).map(BigInteger::from).collect();
+        assert_eq!(xs.iter().sum::<BigInteger>(), big_int!("465"));
+        assert_eq!(
+            xs.iter().product::<BigInteger>(),
+            BigInteger::factorial(30)
+        );
+        assert_eq!(xs.into_iter().rev().sum::<BigInteger>(), big_int!("465"));
+        let empty = std::iter::empty::<BigInteger>();
+        assert_eq!(empty.clone().sum::<BigInteger>(), BigInteger::ZERO);
+        assert_eq!(empty.product::<BigInteger>(), big_int!("1"));
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn num_traits() {
+        use num_traits::*;
+
+        fn horner<T: Num + Clone>(coefficients: &[T], x: &T) -> T {
+            coefficients
+                .iter()
+                .fold(T::zero(), |acc, c| acc * x.clone() + c.clone())
+        }
+
+        let coefficients = [3_i64, -2, 0, 5].map(BigInteger::from);
+        let x = big_int!("10000000000");
+        assert_eq!(
+            horner(&coefficients, &x),
+            big_int!("2999999999800000000000000000005")
+        );
+        assert_eq!(
+            <BigInteger as Num>::from_str_radix("-ff", 16),
+            Ok(big_int!("-255"))
+        );
+        assert!(BigInteger::zero().is_zero() && BigInteger::one().is_one());
+        assert_eq!(Signed::abs(&big_int!("-5")), big_int!("5"));
+        assert_eq!(big_int!("3").abs_sub(&big_int!("5")), BigInteger::ZERO);
+        assert_eq!(Signed::signum(&big_int!("-5")), big_int!("-1"));
+        assert!(big_int!("-5").is_negative());
+        assert_eq!(Pow::pow(&big_int!("-2"), 5_u8), big_int!("-32"));
+        assert_eq!(
+            CheckedDiv::checked_div(&big_int!("7"), &BigInteger::ZERO),
+            None
+        );
+        assert_eq!(
+            big_int!("-7").checked_div(&big_int!("2")),
+            Some(big_int!("-3"))
+        );
+        assert_eq!(big_int!("-7").to_i64(), Some(-7));
+        assert_eq!(big_int!("-7").to_u64(), None);
+        assert_eq!(ToPrimitive::to_f64(&big_int!("12")), Some(12.0));
+        assert_eq!(
+            <BigInteger as FromPrimitive>::from_f64(-2.5),
+            Some(big_int!("-2"))
+        );
+        assert_eq!(
+            <BigInteger as FromPrimitive>::from_u128(u128::MAX),
+            Some(u128::MAX.into())
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn random_values() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let mut rng = StdRng::seed_from_u64(42);
+        assert_eq!(BigInteger::random_bits(0, &mut rng), BigInteger::ZERO);
+        for bits in [1, 63, 64, 65, 1000] {
+            let x = BigInteger::random_bits(bits, &mut rng);
+            assert!(x.bit_length() <= bits && x.signum() >= 0);
+        }
+
+        let bound = big_int!("5");
+        let mut counts = [0; 5];
+        for _ in 0..5000 {
+            let x = BigInteger::random_below(&bound, &mut rng);
+            counts[u8::try_from(&x).unwrap() as usize] += 1;
+        }
+        assert!(counts.iter().all(|&c| (850..1150).contains(&c)), "{counts:?}");
+        let (lo, hi) = (big_int!("-3"), big_int!("-1"));
+        for _ in 0..100 {
+            let x = BigInteger::random_range(&lo, &hi, &mut rng);
+            assert!(x == lo || x == big_int!("-2"));
+        }
+
+        let mut again = StdRng::seed_from_u64(7);
+        let mut rng = StdRng::seed_from_u64(7);
+        assert_eq!(
+            BigInteger::random_bits(300, &mut rng),
+            BigInteger::random_bits(300, &mut again)
+        );
+        let (lo, hi) = (-(big_int!("1") << 3000), big_int!("1") << 3000);
+        for _ in 0..50 {
+            let a = BigInteger::random_range(&lo, &hi, &mut rng);
+            let b = BigInteger::random_range(&lo, &hi, &mut rng);
+            let c = BigInteger::random_range(&lo, &hi, &mut rng);
+            assert_eq!(&(&a + &b) - &b, a);
+            assert_eq!(&a * &(&b + &c), &a * &b + &a * &c);
+            if b.signum() != 0 {
+                let (q, r) = a.div_rem(&b);
+                assert_eq!(q * &b + &r, a);
+                assert_eq!(r.cmp_abs(&b), Ordering::Less);
+            }
+        }
+    }
+
+    #[test]
+    fn radix_conversion() {
+        let mut seed = 11;
+        for n in [65, 200, 1500] {
+            for radix in [2, 7, 10, 36] {
+                let x = random(&mut seed, n);
+                let digits = to_radix(x.digits.to_vec(), radix);
+                assert_eq!(to_radix_digits(&x, radix), digits);
+                let be: Vec<u8> = digits.into_iter().rev().collect();
+                assert_eq!(from_radix_digits(&be, radix), x.digits[..]);
+                let s = (-x.clone()).to_str_radix(radix);
+                assert_eq!(BigInteger::from_str_radix(&s, radix), Ok(-x));
+            }
+        }
+        let power = big_int!("10").pow(5000);
+        let s = power.to_string();
+        assert_eq!(s.len(), 5001);
+        assert!(s.starts_with('1') && s[1..].bytes().all(|b| b == b'0'));
+        assert_eq!(s.parse(), Ok(power.clone()));
+        assert_eq!(power.last_digit(), 1);
+        let zeros = format!("{}{}", "0".repeat(3000), "42");
+        assert_eq!(zeros.parse(), Ok(big_int!("42")));
+    }
+
+    #[test]
+    fn pow() {
+        assert_eq!(big_int!("-3").pow(0), big_int!("1"));
+        assert_eq!(big_int!("-3").pow(5), big_int!("-243"));
+        assert_eq!(big_int!("2").pow(200), big_int!("1") << 200);
+        assert_eq!(
+            big_int!("10").pow(40),
+            big_int!("10000000000000000000000000000000000000000")
+        );
+    }
+
+    #[test]
+    fn roots() {
+        assert_eq!(big_int!("0").isqrt(), big_int!("0"));
+        assert_eq!(big_int!("1").isqrt(), big_int!("1"));
+        assert_eq!(big_int!("15").isqrt(), big_int!("3"));
+        assert_eq!(big_int!("16").isqrt(), big_int!("4"));
+        assert_eq!(big_int!("-27").nth_root(3), big_int!("-3"));
+        assert_eq!(big_int!("-26").nth_root(3), big_int!("-2"));
+        assert_eq!(big_int!("42").nth_root(1), big_int!("42"));
+
+        let x = big_int!("123456789").pow(300) + 1_u8;
+        let root = x.nth_root(300);
+        assert_eq!(root, big_int!("123456789"));
+        let root = x.isqrt();
+        assert!(&root * &root <= x && (&root + 1_u8) * (&root + 1_u8) > x);
+
+        assert!(big_int!("0").is_perfect_square());
+        assert!(!big_int!("-4").is_perfect_square());
+        assert!(big_int!("123456789").pow(40).is_perfect_square());
+        assert!(!(big_int!("123456789").pow(40) + 1_u8).is_perfect_square());
+        assert!(!(big_int!("123456789").pow(40) - 1_u8).is_perfect_square());
+    }
+
+    #[test]
+    fn logarithms() {
+        assert_eq!(big_int!("1").ilog2(), 0);
+        assert_eq!(((big_int!("1") << 1000) - 1_u8).ilog2(), 999);
+        assert_eq!(big_int!("9").ilog10(), 0);
+        assert_eq!(big_int!("10").ilog10(), 1);
+        assert_eq!(big_int!("10").pow(1000).ilog10(), 1000);
+        assert_eq!((big_int!("10").pow(1000) - 1_u8).ilog10(), 999);
+        assert_eq!(big_int!("3").pow(777).ilog(&big_int!("3")), 777);
+        assert_eq!((big_int!("3").pow(777) - 1_u8).ilog(&big_int!("3")), 776);
+        assert_eq!(big_int!("80").ilog(&big_int!("3")), 3);
+        let base = big_int!("1") << 100;
+        assert_eq!(base.pow(7).ilog(&base), 7);
+    }
+
+    #[test]
+    fn primality() {
+        let primes = [
+            "2",
+            "3",
+            "997",
+            "1009",
+            "1000000007",
+            "18446744073709551557",
+            "170141183460469231731687303715884105727",
+        ];
+        for p in primes {
+            assert!(
+                p.parse::<BigInteger>().unwrap().is_probable_prime(10),
+                "{p}"
+            );
+        }
+        let composites = [
+            "-7",
+            "0",
+            "1",
+            "561",
+            "1000001",
+            "3215031751",
+            "3825123056546413051",
+            "18446744073709551617",
+            "340282366920938463463374607431768211457",
+        ];
+        for c in composites {
+            assert!(
+                !c.parse::<BigInteger>().unwrap().is_probable_prime(10),
+                "{c}"
+            );
+        }
+
+        assert_eq!(big_int!("-5").next_prime(), big_int!("2"));
+        assert_eq!(big_int!("2").next_prime(), big_int!("3"));
+        assert_eq!(
+            big_int!("18446744073709551616").next_prime(),
+            big_int!("18446744073709551629")
+        );
+        assert_eq!(
+            big_int!("18446744073709551616").prev_prime(),
+            Some(big_int!("18446744073709551557"))
+        );
+        assert_eq!(big_int!("3").prev_prime(), Some(big_int!("2")));
+        assert_eq!(big_int!("2").prev_prime(), None);
+    }
+
+    #[test]
+    fn factor() {
+        let factors = |x: BigInteger| {
+            x.factor().into_iter().map(|p| p.to_string()).collect::<Vec<_>>()
+        };
+        assert!(big_int!("1").factor().is_empty());
+        assert_eq!(
+            factors(big_int!("-600851475143")),
+            ["71", "839", "1471", "6857"]
+        );
+        assert_eq!(factors(big_int!("1024")), ["2"; 10]);
+        assert_eq!(
+            factors(big_int!("998244353") * big_int!("1000000007")),
+            ["998244353", "1000000007"]
+        );
+        assert_eq!(
+            factors((big_int!("1") << 67) - 1_u8),
+            ["193707721", "761838257287"]
+        );
+        assert_eq!(factors(big_int!("1000000007").pow(3)), ["1000000007"; 3]);
+    }
+
+    #[test]
+    fn limbs() {
+        assert_eq!(
+            big_int!("18446744073709551616") - big_int!("1"),
+            big_int!("18446744073709551615")
+        );
+        assert_eq!(
+            big_int!("18446744073709551615") + big_int!("1"),
+            big_int!("18446744073709551616")
+        );
+        assert_eq!(big_int!("1") << 64, big_int!("18446744073709551616"));
+        assert_eq!(big_int!("-98765432109876543210").last_digit(), 9);
+        assert_eq!(big_int!("-12"), -big_int!("12"));
+    }
+
+    #[test]
+    fn inline_limbs() {
+        let inline = |x: &BigInteger| matches!(x.digits, Limbs::Inline(..));
+        let max = BigInteger::from(u128::MAX);
+        let min = -max.clone();
+        assert!(inline(&max) && inline(&min) && inline(&BigInteger::ZERO));
+
+        let carry = &max + 1_u8;
+        assert_eq!(carry, big_int!("1") << 128);
+        assert!(!inline(&carry));
+        assert_eq!(&carry - 1_u8, max);
+        assert!(inline(&(&carry - 1_u8)));
+        assert_eq!(&min - 1_u8, -carry.clone());
+        assert_eq!(&min + &max, BigInteger::ZERO);
+        assert_eq!(big_int!("5") - &max, big_int!("4") - &max + 1_u8);
+
+        let square = &max * &max;
+        assert_eq!(
+            square,
+            (big_int!("1") << 256) - (big_int!("1") << 129) + 1_u8
+        );
+        assert_eq!(&max * 2_u8, (big_int!("1") << 129) - 2_u8);
+        assert_eq!(&min * -1_i8, max);
+        assert!(inline(&(big_int!("3") * u64::MAX)));
+        assert_eq!(&square / &max, max);
+        assert!(inline(&(&square / &max)));
+        assert_eq!(min.div_rem(&big_int!("10")).1, big_int!("-5"));
+        assert_eq!(&min % 10_u8, big_int!("-5"));
+
+        assert_eq!(big_int!("1") << 127, BigInteger::from(1_u128 << 127));
+        assert_eq!(big_int!("1") << 128, carry);
+        assert_eq!(big_int!("-3") << 127, -(big_int!("3") << 127));
+        assert_eq!(&max >> 127, big_int!("1"));
+        assert_eq!(&max >> 128, BigInteger::ZERO);
+        assert_eq!(&min >> 127, big_int!("-2"));
+        assert_eq!(&min >> 500, big_int!("-1"));
+
+        let mut x = carry.clone();
+        x -= &carry;
+        assert_eq!(x.signum(), 0);
+        assert!(inline(&(&carry >> 100)));
+    }
+
+    #[test]
+    fn parallel() {
+        let tiers = [
+            (usize::MAX, usize::MAX, 4),
+            (32, usize::MAX, usize::MAX),
+            (32, 160, usize::MAX),
+        ]
+        .map(|(karatsuba, toom3, ntt)| MulThresholds {
+            karatsuba,
+            toom3,
+            ntt,
+        });
+        let mut seed = 0x2545f4914f6cdd1d_u64;
+        let n = 3 * PARALLEL_MUL_THRESHOLD;
+        let (x, y) = (random(&mut seed, n), -random(&mut seed, n - 5));
+        let expected = x.mul_with(&y, &tiers[0]);
+        for t in &tiers[1..] {
+            assert_eq!(x.mul_with(&y, t), expected, "{t:?}");
+        }
+
+        let xs: Vec<_> = (0..64).map(|i| random(&mut seed, 40 + i)).collect();
+        let expected = xs.iter().fold(big_int!("1"), |acc, x| acc * x);
+        assert_eq!(BigInteger::product(xs), expected);
+        assert_eq!(BigInteger::product([]), big_int!("1"));
     }
 }
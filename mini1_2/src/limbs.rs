@@ -0,0 +1,122 @@
+use std::ops::{Deref, DerefMut};
+#[cfg(feature = "bench-baseline")]
+use std::sync::atomic::{AtomicBool, Ordering::Relaxed};
+
+use super::*;
+
+/// Number of limbs stored without a heap allocation, enough for 128 bits.
+pub const INLINE_LIMBS: usize = 128 / DIGIT_BITS as usize;
+
+#[cfg(feature = "bench-baseline")]
+static INLINE_ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Turns inline storage on or off for values built afterwards, so that the
+/// allocation bench can measure a heap-only baseline.
+#[cfg(feature = "bench-baseline")]
+pub fn set_inline_limbs(enabled: bool) {
+    INLINE_ENABLED.store(enabled, Relaxed);
+}
+
+#[cfg(feature = "bench-baseline")]
+fn inline_enabled() -> bool {
+    INLINE_ENABLED.load(Relaxed)
+}
+
+#[cfg(not(feature = "bench-baseline"))]
+fn inline_enabled() -> bool {
+    true
+}
+
+/// Little-endian limbs of a magnitude. Short values live inline and move to
+/// the heap only when they outgrow `INLINE_LIMBS`.
+#[derive(Clone)]
+pub(crate) enum Limbs {
+    Inline(u8, [Digit; INLINE_LIMBS]),
+    Heap(Vec<Digit>),
+}
+
+impl Limbs {
+    pub const fn new() -> Self {
+        Limbs::Inline(0, [0; INLINE_LIMBS])
+    }
+
+    pub fn from_u128(x: u128) -> Self {
+        let len = (u128::BITS - x.leading_zeros()).div_ceil(DIGIT_BITS);
+        let data = [x as Digit, (x >> DIGIT_BITS) as Digit];
+        match inline_enabled() {
+            true => Limbs::Inline(len as u8, data),
+            false => Limbs::Heap(data[..len as usize].to_vec()),
+        }
+    }
+
+    pub fn into_vec(self) -> Vec<Digit> {
+        match self {
+            Limbs::Inline(len, data) => data[..len as usize].to_vec(),
+            Limbs::Heap(digits) => digits,
+        }
+    }
+
+    pub fn push(&mut self, digit: Digit) {
+        match self {
+            Limbs::Inline(len, data) if (*len as usize) < INLINE_LIMBS => {
+                data[*len as usize] = digit;
+                *len += 1;
+            }
+            Limbs::Inline(..) => {
+                let mut digits = mem::take(self).into_vec();
+                digits.push(digit);
+                *self = Limbs::Heap(digits);
+            }
+            Limbs::Heap(digits) => digits.push(digit),
+        }
+    }
+
+    /// Drops leading zero limbs.
+    pub fn trim(&mut self) {
+        match self {
+            Limbs::Inline(len, data) => {
+                while *len > 0 && data[*len as usize - 1] == 0 {
+                    *len -= 1;
+                }
+            }
+            Limbs::Heap(digits) => trunc_in_place(digits),
+        }
+    }
+}
+
+impl Default for Limbs {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Vec<Digit>> for Limbs {
+    fn from(digits: Vec<Digit>) -> Self {
+        if digits.len() > INLINE_LIMBS || !inline_enabled() {
+            return Limbs::Heap(digits);
+        }
+        let mut data = [0; INLINE_LIMBS];
+        data[..digits.len()].copy_from_slice(&digits);
+        Limbs::Inline(digits.len() as u8, data)
+    }
+}
+
+impl Deref for Limbs {
+    type Target = [Digit];
+
+    fn deref(&self) -> &[Digit] {
+        match self {
+            Limbs::Inline(len, data) => &data[..*len as usize],
+            Limbs::Heap(digits) => digits,
+        }
+    }
+}
+
+impl DerefMut for Limbs {
+    fn deref_mut(&mut self) -> &mut [Digit] {
+        match self {
+            Limbs::Inline(len, data) => &mut data[..*len as usize],
+            Limbs::Heap(digits) => digits,
+        }
+    }
+}
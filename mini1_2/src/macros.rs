@@ -0,0 +1,65 @@
+macro_rules! forward_assign {
+    ($Assign:ident, $assign:ident) => {
+        forward_assign!(BigInteger, $Assign, $assign);
+    };
+    ($T:ty, $Assign:ident, $assign:ident) => {
+        impl $Assign<$T> for $T {
+            fn $assign(&mut self, rhs: $T) {
+                self.$assign(&rhs);
+            }
+        }
+    };
+}
+
+macro_rules! forward_binop_to_assign {
+    ($Op:ident, $op:ident, $assign:ident) => {
+        impl $Op<BigInteger> for BigInteger {
+            type Output = BigInteger;
+
+            fn $op(mut self, rhs: BigInteger) -> Self::Output {
+                self.$assign(&rhs);
+                self
+            }
+        }
+
+        impl $Op<&BigInteger> for BigInteger {
+            type Output = BigInteger;
+
+            fn $op(mut self, rhs: &BigInteger) -> Self::Output {
+                self.$assign(rhs);
+                self
+            }
+        }
+    };
+}
+
+macro_rules! forward_binop_to_ref {
+    ($Op:ident, $op:ident) => {
+        forward_binop_to_ref!(BigInteger, $Op, $op);
+    };
+    ($T:ty, $Op:ident, $op:ident) => {
+        impl $Op<$T> for $T {
+            type Output = $T;
+
+            fn $op(self, rhs: $T) -> Self::Output {
+                (&self).$op(&rhs)
+            }
+        }
+
+        impl $Op<&$T> for $T {
+            type Output = $T;
+
+            fn $op(self, rhs: &$T) -> Self::Output {
+                (&self).$op(rhs)
+            }
+        }
+
+        impl $Op<$T> for &$T {
+            type Output = $T;
+
+            fn $op(self, rhs: $T) -> Self::Output {
+                self.$op(&rhs)
+            }
+        }
+    };
+}
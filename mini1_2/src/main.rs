@@ -0,0 +1,296 @@
+mod bench;
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cmp::*;
+use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+
+use bench::*;
+use big_integer::*;
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(
+        &self,
+        ptr: *mut u8,
+        layout: Layout,
+        new_size: usize,
+    ) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn main() {
+    do_karatsuba_bench();
+    do_radix_bench();
+    do_allocation_bench();
+}
+
+fn legacy_karatsuba(x: &BigInteger, y: &BigInteger) -> BigInteger {
+    let (l, r) = (x.bit_length().div_ceil(64), y.bit_length().div_ceil(64));
+    if let (0, _) | (_, 0) | (1, 1) = (l, r) {
+        return x * y;
+    }
+
+    let n = (max(l, r) + max(l, r) % 2) as usize;
+    let mask = (BigInteger::from(1_u8) << (n / 2 * 64)) - 1_u8;
+    let (a, b) = (x.clone() >> (n / 2 * 64), x.clone() & mask.clone());
+    let (c, d) = (y.clone() >> (n / 2 * 64), y.clone() & mask);
+    let ac = legacy_karatsuba(&a, &c);
+    let bd = legacy_karatsuba(&b, &d);
+    let adbc = legacy_karatsuba(&(a + b), &(c + d)) - ac.clone() - bd.clone();
+
+    (ac << (n * 64)) + (adbc << (n / 2 * 64)) + bd
+}
+
+fn do_karatsuba_bench() {
+    let thresholds = |karatsuba| MulThresholds {
+        karatsuba,
+        toom3: usize::MAX,
+        ntt: usize::MAX,
+    };
+    let stand = bench_stand![
+        |(a, b): (BigInteger, BigInteger)| {
+            legacy_karatsuba(&a, &b);
+        },
+        move |(a, b): (BigInteger, BigInteger)| {
+            a.mul_with(&b, &thresholds(4));
+        },
+        move |(a, b): (BigInteger, BigInteger)| {
+            a.mul_with(&b, &thresholds(MulThresholds::default().karatsuba));
+        },
+    ];
+
+    let mut report: Vec<Vec<String>> = Vec::new();
+    report.push(
+        ["", "Legacy Karatsuba", "Slice Karatsuba", "Slice Karatsuba (cutoff)"]
+            .iter()
+            .map(ToString::to_string)
+            .collect(),
+    );
+    for p in 2..11 {
+        let n = 2_usize.pow(p);
+        let iterations = max(0x400 / 2_usize.pow(p), 0x4);
+
+        let a = (BigInteger::from(1_u8) << (n * 64)) - 1_u8;
+        let b = (BigInteger::from(1_u8) << (n * 64)) / 3_u8;
+        let stats = stand.run((a, b), iterations);
+        report.push(stats.iter().map(ToString::to_string).collect());
+        report.last_mut().unwrap().insert(0, format!("{n:} digits"));
+    }
+
+    println!("# Karatsuba bench\n");
+    println!("{}", format_table(report));
+}
+
+const CHUNK: u64 = 10_000_000_000_000_000_000;
+
+fn legacy_to_string(x: &BigInteger) -> String {
+    let chunk = BigInteger::from(CHUNK);
+    let (mut x, mut chunks) = (x.clone(), Vec::new());
+    while x.signum() != 0 {
+        let (q, r) = x.div_rem(&chunk);
+        chunks.push(u64::try_from(r).unwrap());
+        x = q;
+    }
+    let mut s = chunks.pop().unwrap_or(0).to_string();
+    for c in chunks.into_iter().rev() {
+        s += &format!("{c:019}");
+    }
+    s
+}
+
+fn legacy_from_str(s: &str) -> BigInteger {
+    let head = s.len() % 19;
+    let chunks = [&s[..head]].into_iter().chain(
+        s.as_bytes()[head..]
+            .chunks(19)
+            .map(|c| std::str::from_utf8(c).unwrap()),
+    );
+    chunks.filter(|c| !c.is_empty()).fold(BigInteger::ZERO, |acc, c| {
+        acc * CHUNK + c.parse::<u64>().unwrap()
+    })
+}
+
+fn do_radix_bench() {
+    let legacy = bench_stand![
+        |(x, _): (BigInteger, String)| {
+            legacy_to_string(&x);
+        },
+        |(_, s): (BigInteger, String)| {
+            legacy_from_str(&s);
+        },
+    ];
+    let stand = bench_stand![
+        |(x, _): (BigInteger, String)| {
+            x.to_string();
+        },
+        |(_, s): (BigInteger, String)| {
+            s.parse::<BigInteger>().unwrap();
+        },
+    ];
+
+    let mut report: Vec<Vec<String>> = Vec::new();
+    report.push(
+        ["", "Legacy to_string", "Legacy from_str", "to_string", "from_str"]
+            .iter()
+            .map(ToString::to_string)
+            .collect(),
+    );
+    for p in 3..7 {
+        let n = 10_usize.pow(p);
+        let iterations = max(0x1000 / n, 0x4);
+
+        let x = BigInteger::from(7_u8).pow((n as f64 / 7_f64.log10()) as u32);
+        let s = x.to_string();
+        let mut row = vec![format!("{} digits", s.len())];
+        match p {
+            ..=5 => legacy.run((x.clone(), s.clone()), iterations).to_vec(),
+            _ => Vec::new(),
+        }
+        .iter()
+        .for_each(|stats| row.push(stats.to_string()));
+        row.resize(3, "-".to_string());
+        let stats = stand.run((x, s), iterations);
+        row.extend(stats.iter().map(ToString::to_string));
+        report.push(row);
+    }
+
+    println!("# Radix conversion bench\n");
+    println!("{}", format_table(report));
+}
+
+fn arithmetic_workload(xs: &[BigInteger]) -> BigInteger {
+    let mut acc = BigInteger::ZERO;
+    for w in xs.windows(2) {
+        let p = &w[0] * &w[1];
+        let q = &p / &(&w[1] + 1_u8);
+        acc += (q - &w[0]) >> 1;
+        acc -= &w[1] % 1000_u32;
+    }
+    acc
+}
+
+/// Switches inline limbs on or off, returning whether the mode is available.
+/// The heap-only baseline needs the `bench-baseline` feature.
+fn set_inline(inline: bool) -> bool {
+    #[cfg(feature = "bench-baseline")]
+    set_inline_limbs(inline);
+    inline || cfg!(feature = "bench-baseline")
+}
+
+fn do_allocation_bench() {
+    let mut seed = 0x2545f4914f6cdd1d_u64;
+    let mut next = || {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        seed
+    };
+    let mut value = |bits: usize| {
+        let limbs = (0..bits.div_ceil(64)).map(|_| BigInteger::from(next()));
+        limbs.fold(BigInteger::ZERO, |acc, x| (acc << 64) + x)
+            >> (bits.div_ceil(64) * 64 - bits)
+    };
+    let n = 10000;
+    let workloads = [
+        ("64-bit", (0..n).map(|_| value(60)).collect::<Vec<_>>()),
+        ("128-bit", (0..n).map(|_| value(120)).collect()),
+        ("mixed, 10% 2048-bit", {
+            (0..n).map(|i| value(if i % 10 == 0 { 2048 } else { 60 })).collect()
+        }),
+        ("2048-bit", (0..n).map(|_| value(2048)).collect()),
+    ];
+
+    let mut report: Vec<Vec<String>> = Vec::new();
+    report.push(
+        [
+            "",
+            "Heap-only allocations per step",
+            "Allocations per step",
+            "Heap-only time",
+            "Time",
+        ]
+        .iter()
+        .map(ToString::to_string)
+        .collect(),
+    );
+    for (name, xs) in workloads {
+        let mut row = vec![name.to_string()];
+        let mut times = Vec::new();
+        for inline in [false, true] {
+            if !set_inline(inline) {
+                row.push("n/a".to_string());
+                times.push("n/a".to_string());
+                continue;
+            }
+            // Rebuild the inputs so that they are stored as in this mode.
+            let xs: Vec<_> = xs
+                .iter()
+                .map(|x| {
+                    BigInteger::from_signed_bytes_le(&x.to_signed_bytes_le())
+                })
+                .collect();
+            let before = ALLOCATIONS.load(Relaxed);
+            arithmetic_workload(&xs);
+            let allocations = ALLOCATIONS.load(Relaxed) - before;
+            row.push(format!("{:.2}", allocations as f64 / (n - 1) as f64));
+            let stats = bench(|| drop(arithmetic_workload(&xs)), 0x10);
+            times.push(stats.to_string());
+        }
+        row.extend(times);
+        report.push(row);
+    }
+    set_inline(true);
+
+    println!("# Allocation bench\n");
+    println!("{}", format_table(report));
+}
+
+pub fn format_table(mut rows: Vec<Vec<String>>) -> String {
+    assert!(!rows.is_empty());
+    let m = rows[0].len();
+    assert!(m > 0);
+    assert!(rows.iter().all(|row| row.len() == m));
+
+    let column_widths = (0..m)
+        .map(|j| rows.iter().map(|row| row[j].trim().len()).max().unwrap_or(0))
+        .collect::<Vec<_>>();
+
+    rows.iter_mut().for_each(|row| {
+        row.iter_mut().enumerate().for_each(|(j, e)| {
+            *e = format!("| {:<width$} ", e.trim(), width = column_widths[j]);
+        });
+        row.push("|".to_string());
+    });
+    rows.insert(
+        1,
+        (0..m)
+            .map(|j| {
+                format!(
+                    "|{:->width$}",
+                    String::new(),
+                    width = column_widths[j] + 2
+                )
+            })
+            .collect(),
+    );
+    rows[1].push("|".to_string());
+
+    rows.into_iter().map(|row| row.join("")).collect::<Vec<_>>().join("\n")
+}
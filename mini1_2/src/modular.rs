@@ -0,0 +1,215 @@
+use super::*;
+
+impl BigInteger {
+    /// Computes `self^exp mod |modulus|` in `0..|modulus|` with
+    /// sliding-window exponentiation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `exp` is negative or `modulus` is zero.
+    pub fn mod_pow(&self, exp: &Self, modulus: &Self) -> Self {
+        assert!(exp.signum() >= 0, "exponent must be non-negative");
+        assert!(modulus.signum() != 0, "modulus must be nonzero");
+        let modulus = modulus.clone().abs();
+        if modulus.bit(0) {
+            let ctx = MontgomeryContext::new(&modulus);
+            ctx.from_montgomery(&ctx.pow(&ctx.to_montgomery(self), exp))
+        } else {
+            BarrettContext::new(&modulus).pow(self, exp)
+        }
+    }
+}
+
+/// Montgomery arithmetic modulo a fixed odd modulus `m`, with `R = 2^(64k)`
+/// for a `k`-limb modulus. Values in Montgomery form are `x * R mod m`.
+#[derive(Clone, Debug)]
+pub struct MontgomeryContext {
+    modulus: BigInteger,
+    inv: Digit,
+    one: BigInteger,
+    r2: BigInteger,
+}
+
+impl MontgomeryContext {
+    /// # Panics
+    ///
+    /// Panics if `modulus` is not positive and odd.
+    pub fn new(modulus: &BigInteger) -> Self {
+        assert!(
+            modulus.signum() > 0 && modulus.bit(0),
+            "modulus must be positive and odd"
+        );
+        let n = modulus.len();
+        let m0 = modulus.digits[0];
+        let mut inv: Digit = m0;
+        for _ in 0..5 {
+            inv = inv.wrapping_mul(2_u64.wrapping_sub(m0.wrapping_mul(inv)));
+        }
+        let r = |n| BigInteger::from(1_u8).shift(n).rem_euclid(modulus);
+        MontgomeryContext {
+            modulus: modulus.clone(),
+            inv: inv.wrapping_neg(),
+            one: r(n),
+            r2: r(2 * n),
+        }
+    }
+
+    pub fn modulus(&self) -> &BigInteger {
+        &self.modulus
+    }
+
+    /// Returns `1` in Montgomery form.
+    pub fn one(&self) -> &BigInteger {
+        &self.one
+    }
+
+    pub fn to_montgomery(&self, x: &BigInteger) -> BigInteger {
+        self.mul(&x.rem_euclid(&self.modulus), &self.r2)
+    }
+
+    pub fn from_montgomery(&self, x: &BigInteger) -> BigInteger {
+        self.redc(x.digits.to_vec())
+    }
+
+    /// Multiplies two values in Montgomery form, both in `0..m`.
+    pub fn mul(&self, x: &BigInteger, y: &BigInteger) -> BigInteger {
+        self.redc((x * y).digits.into_vec())
+    }
+
+    pub fn square(&self, x: &BigInteger) -> BigInteger {
+        self.mul(x, x)
+    }
+
+    /// Raises a value in Montgomery form to `exp`, returning Montgomery form.
+    pub fn pow(&self, x: &BigInteger, exp: &BigInteger) -> BigInteger {
+        assert!(exp.signum() >= 0, "exponent must be non-negative");
+        sliding_window_pow(x.clone(), exp, self.one.clone(), |a, b| {
+            self.mul(a, b)
+        })
+    }
+
+    fn redc(&self, mut t: Vec<Digit>) -> BigInteger {
+        let m = &self.modulus.digits;
+        let n = m.len();
+        t.resize(2 * n + 1, 0);
+        for i in 0..n {
+            let u = t[i].wrapping_mul(self.inv) as DoubleDigit;
+            let mut carry = 0;
+            for (j, &d) in m.iter().enumerate() {
+                let s = t[i + j] as DoubleDigit + u * d as DoubleDigit + carry;
+                t[i + j] = s as Digit;
+                carry = s >> DIGIT_BITS;
+            }
+            add_slice(&mut t[i + n..], &[carry as Digit]);
+        }
+        let mut r = trunc(t.split_off(n));
+        if compare(&r, m) != Ordering::Less {
+            sub_assign(&mut r, m);
+        }
+        BigInteger::from_parts(r, 1)
+    }
+}
+
+/// Barrett reduction modulo a fixed positive modulus `m` of `k` limbs, using
+/// the precomputed `mu = floor(2^(128k) / m)`.
+#[derive(Clone, Debug)]
+pub struct BarrettContext {
+    modulus: BigInteger,
+    mu: BigInteger,
+}
+
+impl BarrettContext {
+    /// # Panics
+    ///
+    /// Panics if `modulus` is not positive.
+    pub fn new(modulus: &BigInteger) -> Self {
+        assert!(modulus.signum() > 0, "modulus must be positive");
+        let mu = BigInteger::from(1_u8).shift(2 * modulus.len()) / modulus;
+        BarrettContext { modulus: modulus.clone(), mu }
+    }
+
+    pub fn modulus(&self) -> &BigInteger {
+        &self.modulus
+    }
+
+    /// Reduces `x` modulo `m`.
+    ///
+    /// # Panics
+    ///
+    /// Panics unless `0 <= x < 2^(128k)`, which covers any product of two
+    /// reduced values.
+    pub fn reduce(&self, x: &BigInteger) -> BigInteger {
+        let k = self.modulus.len();
+        assert!(x.signum() >= 0 && x.len() <= 2 * k, "value out of range");
+        let high = |x: &BigInteger, n: usize| {
+            BigInteger::from_parts(x.digits.get(n..).unwrap_or(&[]).to_vec(), 1)
+        };
+        let q = high(&(&high(x, k - 1) * &self.mu), k + 1);
+        let mut r = x - &(&q * &self.modulus);
+        while r >= self.modulus {
+            r -= &self.modulus;
+        }
+        r
+    }
+
+    /// Multiplies two values in `0..m` modulo `m`.
+    pub fn mul(&self, x: &BigInteger, y: &BigInteger) -> BigInteger {
+        self.reduce(&(x * y))
+    }
+
+    pub fn square(&self, x: &BigInteger) -> BigInteger {
+        self.mul(x, x)
+    }
+
+    /// Computes `x^exp mod m` in `0..m`.
+    pub fn pow(&self, x: &BigInteger, exp: &BigInteger) -> BigInteger {
+        assert!(exp.signum() >= 0, "exponent must be non-negative");
+        let one = BigInteger::from(1_u8).rem_euclid(&self.modulus);
+        let x = x.rem_euclid(&self.modulus);
+        sliding_window_pow(x, exp, one, |a, b| self.mul(a, b))
+    }
+}
+
+pub(crate) fn sliding_window_pow<T: Clone>(
+    base: T,
+    exp: &BigInteger,
+    one: T,
+    mul: impl Fn(&T, &T) -> T,
+) -> T {
+    let bits = exp.bit_length();
+    let k = match bits {
+        0..=24 => 1,
+        25..=80 => 3,
+        81..=240 => 4,
+        241..=672 => 5,
+        _ => 6,
+    };
+
+    let square = mul(&base, &base);
+    let mut table = vec![base];
+    for i in 1..1 << (k - 1) {
+        table.push(mul(&table[i - 1], &square));
+    }
+
+    let mut result = one;
+    let mut i = bits;
+    while i > 0 {
+        if !exp.bit(i - 1) {
+            result = mul(&result, &result);
+            i -= 1;
+            continue;
+        }
+        let mut j = i.saturating_sub(k);
+        while !exp.bit(j) {
+            j += 1;
+        }
+        let mut window = 0;
+        for b in (j..i).rev() {
+            result = mul(&result, &result);
+            window = window << 1 | exp.bit(b) as usize;
+        }
+        result = mul(&result, &table[window >> 1]);
+        i = j;
+    }
+    result
+}
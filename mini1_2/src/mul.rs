@@ -0,0 +1,393 @@
+use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+
+use super::*;
+
+/// Operand sizes, in 64-bit digits of the shorter operand, from which each
+/// multiplication algorithm takes over from the previous one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MulThresholds {
+    pub karatsuba: usize,
+    pub toom3: usize,
+    pub ntt: usize,
+}
+
+const DEFAULT_THRESHOLDS: MulThresholds =
+    MulThresholds { karatsuba: 32, toom3: 160, ntt: 2048 };
+
+impl Default for MulThresholds {
+    fn default() -> Self {
+        DEFAULT_THRESHOLDS
+    }
+}
+
+/// Size, in 64-bit digits of the shorter operand, from which the `parallel`
+/// feature computes independent sub-products on separate threads.
+pub const PARALLEL_MUL_THRESHOLD: usize = 512;
+
+/// Runs `a` and `b`, on separate threads with the `parallel` feature while
+/// fewer threads are busy than the machine has cores.
+pub(crate) fn join<A, B, RA, RB>(a: A, b: B) -> (RA, RB)
+where
+    A: FnOnce() -> RA + Send,
+    B: FnOnce() -> RB + Send,
+    RA: Send,
+    RB: Send,
+{
+    #[cfg(feature = "parallel")]
+    {
+        static BUSY: AtomicUsize = AtomicUsize::new(1);
+        let cores = std::thread::available_parallelism().map_or(1, |n| n.get());
+        if BUSY.fetch_add(1, Relaxed) < cores {
+            let r = std::thread::scope(|s| {
+                let a = s.spawn(a);
+                let b = b();
+                let a =
+                    a.join().unwrap_or_else(|e| std::panic::resume_unwind(e));
+                (a, b)
+            });
+            BUSY.fetch_sub(1, Relaxed);
+            return r;
+        }
+        BUSY.fetch_sub(1, Relaxed);
+    }
+    (a(), b())
+}
+
+static KARATSUBA_THRESHOLD: AtomicUsize =
+    AtomicUsize::new(DEFAULT_THRESHOLDS.karatsuba);
+static TOOM3_THRESHOLD: AtomicUsize =
+    AtomicUsize::new(DEFAULT_THRESHOLDS.toom3);
+static NTT_THRESHOLD: AtomicUsize = AtomicUsize::new(DEFAULT_THRESHOLDS.ntt);
+
+/// Thresholds used by the `*` operators.
+pub fn mul_thresholds() -> MulThresholds {
+    MulThresholds {
+        karatsuba: KARATSUBA_THRESHOLD.load(Relaxed),
+        toom3: TOOM3_THRESHOLD.load(Relaxed),
+        ntt: NTT_THRESHOLD.load(Relaxed),
+    }
+}
+
+/// Changes thresholds used by the `*` operators.
+///
+/// # Panics
+///
+/// Panics if any threshold is less than 4.
+pub fn set_mul_thresholds(thresholds: MulThresholds) {
+    thresholds.validate();
+    KARATSUBA_THRESHOLD.store(thresholds.karatsuba, Relaxed);
+    TOOM3_THRESHOLD.store(thresholds.toom3, Relaxed);
+    NTT_THRESHOLD.store(thresholds.ntt, Relaxed);
+}
+
+impl MulThresholds {
+    fn validate(&self) {
+        assert!(
+            min(self.karatsuba, min(self.toom3, self.ntt)) >= 4,
+            "multiplication thresholds must be at least 4"
+        );
+    }
+}
+
+impl BigInteger {
+    /// Multiplies with explicitly given algorithm thresholds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any threshold is less than 4.
+    pub fn mul_with(&self, rhs: &Self, thresholds: &MulThresholds) -> Self {
+        thresholds.validate();
+        self.mul_unchecked(rhs, thresholds)
+    }
+
+    fn mul_unchecked(&self, rhs: &Self, thresholds: &MulThresholds) -> Self {
+        let small = self.to_u128().zip(rhs.to_u128());
+        if let Some(p) = small.and_then(|(x, y)| x.checked_mul(y)) {
+            return Self::from_u128(p, self.signum * rhs.signum);
+        }
+        Self::from_parts(
+            mul_digits(&self.digits, &rhs.digits, thresholds),
+            self.signum * rhs.signum,
+        )
+    }
+}
+
+impl Mul<&BigInteger> for &BigInteger {
+    type Output = BigInteger;
+
+    fn mul(self, rhs: &BigInteger) -> Self::Output {
+        self.mul_unchecked(rhs, &mul_thresholds())
+    }
+}
+
+fn mul_digits(x: &[Digit], y: &[Digit], t: &MulThresholds) -> Vec<Digit> {
+    let (x, y) = if x.len() < y.len() { (y, x) } else { (x, y) };
+    if y.is_empty() {
+        Vec::new()
+    } else if y.len() < t.karatsuba {
+        schoolbook(x, y)
+    } else if y.len() >= t.ntt {
+        ntt_mul(x, y)
+    } else if x.len() >= 2 * y.len() {
+        unbalanced(x, y, t)
+    } else if y.len() < t.toom3 {
+        karatsuba(x, y, t)
+    } else {
+        toom3(x, y, t)
+    }
+}
+
+fn schoolbook(x: &[Digit], y: &[Digit]) -> Vec<Digit> {
+    let mut r = vec![0; x.len() + y.len()];
+    schoolbook_into(&mut r, x, y);
+    r
+}
+
+fn schoolbook_into(r: &mut [Digit], x: &[Digit], y: &[Digit]) {
+    r.fill(0);
+    for (i, &a) in x.iter().enumerate() {
+        let mut carry: DoubleDigit = 0;
+        for (j, &b) in y.iter().enumerate() {
+            carry +=
+                a as DoubleDigit * b as DoubleDigit + r[i + j] as DoubleDigit;
+            r[i + j] = carry as Digit;
+            carry >>= DIGIT_BITS;
+        }
+        r[i + y.len()] = carry as Digit;
+    }
+}
+
+fn unbalanced(x: &[Digit], y: &[Digit], t: &MulThresholds) -> Vec<Digit> {
+    let mut r = Vec::with_capacity(x.len() + y.len());
+    for (i, chunk) in x.chunks(y.len()).enumerate() {
+        add_shifted(&mut r, &mul_digits(chunk, y, t), i * y.len());
+    }
+    r
+}
+
+fn karatsuba(x: &[Digit], y: &[Digit], t: &MulThresholds) -> Vec<Digit> {
+    let mut r = vec![0; x.len() + y.len()];
+    let mut scratch = vec![0; karatsuba_scratch_len(x.len())];
+    karatsuba_into(&mut r, x, y, &mut scratch, t.karatsuba);
+    r
+}
+
+fn karatsuba_scratch_len(n: usize) -> usize {
+    match n {
+        0..=3 => 0,
+        _ => {
+            let m = n.div_ceil(2) + 1;
+            4 * m + karatsuba_scratch_len(m)
+        }
+    }
+}
+
+fn karatsuba_into(
+    r: &mut [Digit],
+    x: &[Digit],
+    y: &[Digit],
+    scratch: &mut [Digit],
+    threshold: usize,
+) {
+    let m = x.len().div_ceil(2);
+    if y.len() < threshold {
+        schoolbook_into(r, x, y);
+        return;
+    }
+    if y.len() <= m {
+        r.fill(0);
+        let (product, scratch) = scratch.split_at_mut(2 * y.len());
+        for (i, chunk) in x.chunks(y.len()).enumerate() {
+            let product = &mut product[..chunk.len() + y.len()];
+            karatsuba_into(product, y, chunk, scratch, threshold);
+            add_slice(&mut r[i * y.len()..], product);
+        }
+        return;
+    }
+
+    let (x0, x1) = x.split_at(m);
+    let (y0, y1) = y.split_at(m);
+    let (z0, z2) = r.split_at_mut(2 * m);
+    let (sx, scratch) = scratch.split_at_mut(m + 1);
+    let (sy, scratch) = scratch.split_at_mut(m + 1);
+    let (z1, scratch) = scratch.split_at_mut(2 * m + 2);
+    sx[..m].copy_from_slice(x0);
+    sx[m] = add_slice(&mut sx[..m], x1) as Digit;
+    sy[..m].copy_from_slice(y0);
+    sy[m] = add_slice(&mut sy[..m], y1) as Digit;
+
+    if cfg!(feature = "parallel") && y.len() >= PARALLEL_MUL_THRESHOLD {
+        // Each branch gets its own scratch so that they can run concurrently.
+        let len = karatsuba_scratch_len(m);
+        join(
+            || karatsuba_into(z0, x0, y0, &mut vec![0; len], threshold),
+            || {
+                join(
+                    || karatsuba_into(z2, x1, y1, &mut vec![0; len], threshold),
+                    || karatsuba_into(z1, sx, sy, scratch, threshold),
+                )
+            },
+        );
+    } else {
+        karatsuba_into(z0, x0, y0, scratch, threshold);
+        karatsuba_into(z2, x1, y1, scratch, threshold);
+        karatsuba_into(z1, sx, sy, scratch, threshold);
+    }
+
+    sub_slice(z1, &r[..2 * m]);
+    sub_slice(z1, &r[2 * m..]);
+    let len = z1.iter().rposition(|&d| d != 0).map_or(0, |i| i + 1);
+    add_slice(&mut r[m..], &z1[..len]);
+}
+
+fn toom3(x: &[Digit], y: &[Digit], t: &MulThresholds) -> Vec<Digit> {
+    let k = max(x.len(), y.len()).div_ceil(3);
+    let split = |x: &[Digit]| {
+        let mut parts =
+            x.chunks(k).map(|p| BigInteger::from_parts(p.to_vec(), 1));
+        [(); 3].map(|_| parts.next().unwrap_or_default())
+    };
+    let evaluate = |[x0, x1, x2]: [BigInteger; 3]| {
+        let x02 = &x0 + &x2;
+        let p1 = &x02 + &x1;
+        let pm1 = x02 - &x1;
+        let pm2 = ((&pm1 + &x2) << 1) - &x0;
+        [x0, p1, pm1, pm2, x2]
+    };
+    let [p0, p1, pm1, pm2, pinf] = evaluate(split(x));
+    let [q0, q1, qm1, qm2, qinf] = evaluate(split(y));
+
+    let mul = |p: BigInteger, q: BigInteger| p.mul_unchecked(&q, t);
+    let ((r0, r1), (rm1, (rm2, r4))) = if cfg!(feature = "parallel")
+        && min(x.len(), y.len()) >= PARALLEL_MUL_THRESHOLD
+    {
+        join(
+            || join(|| mul(p0, q0), || mul(p1, q1)),
+            || {
+                join(
+                    || mul(pm1, qm1),
+                    || join(|| mul(pm2, qm2), || mul(pinf, qinf)),
+                )
+            },
+        )
+    } else {
+        (
+            (mul(p0, q0), mul(p1, q1)),
+            (mul(pm1, qm1), (mul(pm2, qm2), mul(pinf, qinf))),
+        )
+    };
+
+    let r3 = (rm2 - &r1) / 3_u8;
+    let r1 = (r1 - &rm1) / 2_u8;
+    let r2 = rm1 - &r0;
+    let r3 = (&r2 - r3) / 2_u8 + (&r4 << 1);
+    let r2 = r2 + &r1 - &r4;
+    let r1 = r1 - &r3;
+
+    (r0 + r1.shift(k) + r2.shift(2 * k) + r3.shift(3 * k) + r4.shift(4 * k))
+        .digits
+        .into_vec()
+}
+
+const NTT_PRIMES: [(u64, u64); 3] = [
+    (4179340454199820289, 3),
+    (4512606826625236993, 7),
+    (4546383823830515713, 10),
+];
+
+fn mul_mod(x: u64, y: u64, p: u64) -> u64 {
+    (x as u128 * y as u128 % p as u128) as u64
+}
+
+fn pow_mod(mut x: u64, mut e: u64, p: u64) -> u64 {
+    let mut r = 1;
+    while e > 0 {
+        if e & 1 == 1 {
+            r = mul_mod(r, x, p);
+        }
+        x = mul_mod(x, x, p);
+        e >>= 1;
+    }
+    r
+}
+
+fn ntt(a: &mut [u64], (p, g): (u64, u64), invert: bool) {
+    let n = a.len();
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j ^= bit;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let w = pow_mod(g, (p - 1) / len as u64, p);
+        let w = if invert { pow_mod(w, p - 2, p) } else { w };
+        for chunk in a.chunks_mut(len) {
+            let (lo, hi) = chunk.split_at_mut(len / 2);
+            let mut wn = 1;
+            for (u, v) in lo.iter_mut().zip(hi) {
+                let t = mul_mod(*v, wn, p);
+                *v = if *u >= t { *u - t } else { *u + (p - t) };
+                *u = if *u >= p - t { *u - (p - t) } else { *u + t };
+                wn = mul_mod(wn, w, p);
+            }
+        }
+        len <<= 1;
+    }
+
+    if invert {
+        let inv = pow_mod(n as u64, p - 2, p);
+        a.iter_mut().for_each(|x| *x = mul_mod(*x, inv, p));
+    }
+}
+
+fn ntt_mul(x: &[Digit], y: &[Digit]) -> Vec<Digit> {
+    let n = x.len() + y.len();
+    let residues = NTT_PRIMES.map(|(p, g)| {
+        let mut a = x.iter().map(|&d| d % p).collect::<Vec<_>>();
+        let mut b = y.iter().map(|&d| d % p).collect::<Vec<_>>();
+        a.resize(n.next_power_of_two(), 0);
+        b.resize(n.next_power_of_two(), 0);
+        ntt(&mut a, (p, g), false);
+        ntt(&mut b, (p, g), false);
+        a.iter_mut().zip(&b).for_each(|(a, &b)| *a = mul_mod(*a, b, p));
+        ntt(&mut a, (p, g), true);
+        a
+    });
+
+    let [(p1, _), (p2, _), (p3, _)] = NTT_PRIMES;
+    let inv12 = pow_mod(p1 % p2, p2 - 2, p2);
+    let inv13 = pow_mod(p1 % p3, p3 - 2, p3);
+    let inv23 = pow_mod(p2 % p3, p3 - 2, p3);
+    let p12 = p1 as DoubleDigit * p2 as DoubleDigit;
+
+    let mut r = Vec::with_capacity(n + 2);
+    let [r1, r2, r3] = residues;
+    let coefficients = r1.into_iter().zip(r2).zip(r3);
+    for (i, ((a1, a2), a3)) in coefficients.take(n - 1).enumerate() {
+        let v2 = mul_mod((a2 + p2 - a1 % p2) % p2, inv12, p2);
+        let v3 = mul_mod((a3 + p3 - a1 % p3) % p3, inv13, p3);
+        let v3 = mul_mod((v3 + p3 - v2 % p3) % p3, inv23, p3);
+
+        let low = a1 as DoubleDigit + v2 as DoubleDigit * p1 as DoubleDigit;
+        let lo = v3 as DoubleDigit * (p12 as Digit) as DoubleDigit;
+        let hi = v3 as DoubleDigit * (p12 >> DIGIT_BITS);
+        let mid = (lo >> DIGIT_BITS) + (hi as Digit) as DoubleDigit;
+        let high = [
+            lo as Digit,
+            mid as Digit,
+            ((mid >> DIGIT_BITS) + (hi >> DIGIT_BITS)) as Digit,
+        ];
+        add_shifted(&mut r, &high, i);
+        add_shifted(&mut r, &[low as Digit, (low >> DIGIT_BITS) as Digit], i);
+    }
+    r
+}
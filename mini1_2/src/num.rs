@@ -0,0 +1,123 @@
+use num_traits::{
+    CheckedDiv, FromPrimitive, Num, One, Pow, Signed, ToPrimitive, Zero,
+};
+
+use super::*;
+
+impl Zero for BigInteger {
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        self.signum() == 0
+    }
+}
+
+impl One for BigInteger {
+    fn one() -> Self {
+        Self::from(1_u8)
+    }
+}
+
+impl Num for BigInteger {
+    type FromStrRadixErr = ParseBigIntError;
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseBigIntError> {
+        BigInteger::from_str_radix(s, radix)
+    }
+}
+
+impl Signed for BigInteger {
+    fn abs(&self) -> Self {
+        self.clone().abs()
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        max(self - other, Self::ZERO)
+    }
+
+    fn signum(&self) -> Self {
+        Self::from(BigInteger::signum(self))
+    }
+
+    fn is_positive(&self) -> bool {
+        BigInteger::signum(self) > 0
+    }
+
+    fn is_negative(&self) -> bool {
+        BigInteger::signum(self) < 0
+    }
+}
+
+macro_rules! impl_pow {
+    ($($t:ty),*) => {$(
+        impl Pow<$t> for BigInteger {
+            type Output = BigInteger;
+
+            fn pow(self, exp: $t) -> Self::Output {
+                BigInteger::pow(&self, exp.into())
+            }
+        }
+
+        impl Pow<$t> for &BigInteger {
+            type Output = BigInteger;
+
+            fn pow(self, exp: $t) -> Self::Output {
+                BigInteger::pow(self, exp.into())
+            }
+        }
+    )*};
+}
+
+impl_pow!(u8, u16, u32);
+
+impl CheckedDiv for BigInteger {
+    fn checked_div(&self, v: &Self) -> Option<Self> {
+        self.checked_div_rem(v).map(|(q, _)| q)
+    }
+}
+
+impl ToPrimitive for BigInteger {
+    fn to_i64(&self) -> Option<i64> {
+        self.try_into().ok()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.try_into().ok()
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        self.try_into().ok()
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        self.try_into().ok()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        Some(BigInteger::to_f64(self))
+    }
+}
+
+impl FromPrimitive for BigInteger {
+    fn from_i64(n: i64) -> Option<Self> {
+        Some(n.into())
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(n.into())
+    }
+
+    fn from_i128(n: i128) -> Option<Self> {
+        Some(n.into())
+    }
+
+    fn from_u128(n: u128) -> Option<Self> {
+        Some(n.into())
+    }
+
+    fn from_f64(n: f64) -> Option<Self> {
+        BigInteger::from_f64(n)
+    }
+}
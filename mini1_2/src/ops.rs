@@ -1,6 +1,11 @@
 use std::cmp::*;
 
-#[macro_export()]
+pub type Digit = u64;
+pub type DoubleDigit = u128;
+
+pub const DIGIT_BITS: u32 = Digit::BITS;
+
+#[macro_export]
 macro_rules! swap_by_len {
     ($x:ident, $y: ident) => {
         if $x.len() < $y.len() {
@@ -11,63 +16,261 @@ macro_rules! swap_by_len {
     };
 }
 
-pub fn lsh(x: Vec<i8>, n: usize) -> Vec<i8> {
-    let mut r = Vec::new();
-    r.resize(x.len() + n, 0);
-    for (i, d) in x.into_iter().enumerate() {
-        r[i + n] = d;
+pub fn lsh(x: Vec<Digit>, n: usize) -> Vec<Digit> {
+    if x.is_empty() {
+        return x;
     }
+    let mut r = Vec::with_capacity(x.len() + n);
+    r.resize(n, 0);
+    r.extend(x);
     r
 }
 
-pub fn addition(x: Vec<i8>, y: Vec<i8>) -> Vec<i8> {
-    let (mut x, y) = swap_by_len!(x, y);
-    for (i, d) in y.iter().copied().enumerate() {
-        x[i] += d
+pub fn add_assign(x: &mut Vec<Digit>, y: &[Digit]) {
+    add_shifted(x, y, 0);
+}
+
+pub fn add_shifted(x: &mut Vec<Digit>, y: &[Digit], offset: usize) {
+    if x.len() < offset + y.len() {
+        x.resize(offset + y.len(), 0);
+    }
+    if add_slice(&mut x[offset..], y) {
+        x.push(1);
     }
-    carry(x)
 }
 
-pub fn substraction(mut x: Vec<i8>, y: Vec<i8>) -> (Vec<i8>, i8) {
-    x.resize(max(x.len(), y.len()), 0);
-    for (i, d) in y.iter().copied().enumerate() {
-        x[i] -= d
+pub fn add_slice(x: &mut [Digit], y: &[Digit]) -> bool {
+    let mut c = false;
+    for (i, d) in x.iter_mut().enumerate() {
+        if i >= y.len() && !c {
+            break;
+        }
+        let e = y.get(i).copied().unwrap_or(0);
+        let (s, c1) = d.overflowing_add(e);
+        let (s, c2) = s.overflowing_add(c as Digit);
+        *d = s;
+        c = c1 || c2;
     }
-    let mut c = 0;
-    for d in x.iter_mut() {
-        *d += c;
-        if *d < 0 {
-            *d += 10;
-            c = -1;
-        } else {
-            c = 0;
+    c
+}
+
+pub fn sub_slice(x: &mut [Digit], y: &[Digit]) -> bool {
+    let mut b = false;
+    for (i, d) in x.iter_mut().enumerate() {
+        if i >= y.len() && !b {
+            break;
         }
+        let e = y.get(i).copied().unwrap_or(0);
+        let (s, b1) = d.overflowing_sub(e);
+        let (s, b2) = s.overflowing_sub(b as Digit);
+        *d = s;
+        b = b1 || b2;
+    }
+    b
+}
+
+pub fn sub_assign(x: &mut Vec<Digit>, y: &[Digit]) {
+    assert!(!sub_slice(x, y));
+    trunc_in_place(x);
+}
+
+pub fn rsub_assign(x: &mut Vec<Digit>, y: &[Digit]) {
+    x.resize(y.len(), 0);
+    let mut b = false;
+    for (d, e) in x.iter_mut().zip(y) {
+        let (s, b1) = e.overflowing_sub(*d);
+        let (s, b2) = s.overflowing_sub(b as Digit);
+        *d = s;
+        b = b1 || b2;
+    }
+    assert!(!b);
+    trunc_in_place(x);
+}
+
+pub fn compare(x: &[Digit], y: &[Digit]) -> Ordering {
+    x.len().cmp(&y.len()).then_with(|| x.iter().rev().cmp(y.iter().rev()))
+}
+
+pub fn mul_small(mut x: Vec<Digit>, m: Digit, a: Digit) -> Vec<Digit> {
+    let carry = mul_small_in_place(&mut x, m, a);
+    if carry != 0 {
+        x.push(carry);
     }
-    if c != 0 {
-        let (x, a) = substraction(lsh(vec![1], x.len()), x);
-        assert!(a == 1);
-        (x, -1)
-    } else {
-        (carry(x), 1)
+    trunc(x)
+}
+
+/// Computes `x * m + a` in place, returning the digit carried out of the top.
+pub fn mul_small_in_place(x: &mut [Digit], m: Digit, a: Digit) -> Digit {
+    let mut carry = a as DoubleDigit;
+    for d in x.iter_mut() {
+        carry += *d as DoubleDigit * m as DoubleDigit;
+        *d = carry as Digit;
+        carry >>= DIGIT_BITS;
     }
+    carry as Digit
+}
+
+pub fn rem_small(x: &[Digit], m: Digit) -> Digit {
+    x.iter().rev().fold(0, |rem, &d| {
+        (((rem as DoubleDigit) << DIGIT_BITS | d as DoubleDigit)
+            % m as DoubleDigit) as Digit
+    })
 }
 
-pub fn carry(mut x: Vec<i8>) -> Vec<i8> {
+pub fn div_small(x: &mut [Digit], m: Digit) -> Digit {
+    let mut rem: DoubleDigit = 0;
+    for d in x.iter_mut().rev() {
+        rem = (rem << DIGIT_BITS) | *d as DoubleDigit;
+        *d = (rem / m as DoubleDigit) as Digit;
+        rem %= m as DoubleDigit;
+    }
+    rem as Digit
+}
+
+pub fn shl_bits(mut x: Vec<Digit>, n: u32) -> Vec<Digit> {
+    if n == 0 || x.is_empty() {
+        return x;
+    }
     let mut carry = 0;
     for d in x.iter_mut() {
-        *d += carry;
-        carry = *d / 10;
-        *d %= 10;
+        let e = *d;
+        *d = e << n | carry;
+        carry = e >> (DIGIT_BITS - n);
     }
     if carry != 0 {
-        x.push(carry)
+        x.push(carry);
     }
     x
 }
 
-pub fn trunc(mut x: Vec<i8>) -> Vec<i8> {
-    while let Some(0) = x.last() {
-        x.pop();
+pub fn shr_bits(mut x: Vec<Digit>, n: u32) -> Vec<Digit> {
+    if n == 0 {
+        return x;
+    }
+    let mut carry = 0;
+    for d in x.iter_mut().rev() {
+        let e = *d;
+        *d = e >> n | carry;
+        carry = e << (DIGIT_BITS - n);
+    }
+    trunc(x)
+}
+
+pub fn long_division(x: Vec<Digit>, y: &[Digit]) -> (Vec<Digit>, Vec<Digit>) {
+    assert!(!y.is_empty());
+    if compare(&x, y) == Ordering::Less {
+        return (Vec::new(), x);
+    }
+    if y.len() == 1 {
+        let mut q = x;
+        let r = div_small(&mut q, y[0]);
+        return (trunc(q), trunc(vec![r]));
+    }
+
+    let s = y.last().unwrap().leading_zeros();
+    let v = shl_bits(y.to_vec(), s);
+    let mut u = shl_bits(x, s);
+    u.push(0);
+    let n = v.len();
+    let m = u.len() - n;
+    let base = 1 << DIGIT_BITS;
+    let (v1, v2) = (v[n - 1] as DoubleDigit, v[n - 2] as DoubleDigit);
+
+    let mut q = vec![0; m];
+    for j in (0..m).rev() {
+        let top = (u[j + n] as DoubleDigit) << DIGIT_BITS
+            | u[j + n - 1] as DoubleDigit;
+        let (mut qhat, mut rhat) = (top / v1, top % v1);
+        while qhat >= base
+            || qhat * v2 > (rhat << DIGIT_BITS | u[j + n - 2] as DoubleDigit)
+        {
+            qhat -= 1;
+            rhat += v1;
+            if rhat >= base {
+                break;
+            }
+        }
+
+        let (mut carry, mut borrow) = (0, false);
+        for i in 0..n {
+            let p = qhat * v[i] as DoubleDigit + carry;
+            carry = p >> DIGIT_BITS;
+            let (t, b1) = u[i + j].overflowing_sub(p as Digit);
+            let (t, b2) = t.overflowing_sub(borrow as Digit);
+            u[i + j] = t;
+            borrow = b1 || b2;
+        }
+        let (t, b1) = u[j + n].overflowing_sub(carry as Digit);
+        let (t, b2) = t.overflowing_sub(borrow as Digit);
+        u[j + n] = t;
+
+        if b1 || b2 {
+            qhat -= 1;
+            let mut c = false;
+            for i in 0..n {
+                let (t, c1) = u[i + j].overflowing_add(v[i]);
+                let (t, c2) = t.overflowing_add(c as Digit);
+                u[i + j] = t;
+                c = c1 || c2;
+            }
+            u[j + n] = u[j + n].wrapping_add(c as Digit);
+        }
+        q[j] = qhat as Digit;
+    }
+
+    u.truncate(n);
+    (trunc(q), shr_bits(u, s))
+}
+
+pub fn from_radix(digits: &[u8], radix: u32) -> Vec<Digit> {
+    let (_, n) = radix_chunk(radix);
+    let head = digits.len() % n;
+    let mut x = Vec::new();
+    for chunk in [&digits[..head]].into_iter().chain(digits[head..].chunks(n)) {
+        let m = (radix as Digit).pow(chunk.len() as u32);
+        let a = chunk.iter().fold(0, |a, &d| a * radix as Digit + d as Digit);
+        x = mul_small(x, m, a);
     }
     x
 }
+
+pub fn radix_chunk(radix: u32) -> (Digit, usize) {
+    let (mut base, mut n) = (radix as Digit, 1);
+    while let Some(b) = base.checked_mul(radix as Digit) {
+        base = b;
+        n += 1;
+    }
+    (base, n)
+}
+
+pub fn to_radix(mut x: Vec<Digit>, radix: u32) -> Vec<u8> {
+    let (base, n) = radix_chunk(radix);
+    let mut digits = Vec::new();
+    while !x.is_empty() {
+        let mut chunk = div_small(&mut x, base);
+        x = trunc(x);
+        for _ in 0..n {
+            digits.push((chunk % radix as Digit) as u8);
+            chunk /= radix as Digit;
+        }
+    }
+    trunc(digits)
+}
+
+pub fn negate(x: &mut [Digit]) {
+    let mut c = true;
+    for d in x.iter_mut() {
+        (*d, c) = (!*d).overflowing_add(c as Digit);
+    }
+}
+
+pub fn trunc<T: Default + PartialEq>(mut x: Vec<T>) -> Vec<T> {
+    trunc_in_place(&mut x);
+    x
+}
+
+pub fn trunc_in_place<T: Default + PartialEq>(x: &mut Vec<T>) {
+    while x.last().is_some_and(|d| *d == T::default()) {
+        x.pop();
+    }
+}
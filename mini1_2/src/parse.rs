@@ -0,0 +1,173 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use super::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseBigIntError {
+    Empty,
+    InvalidRadix(u32),
+    InvalidDigit { digit: char, offset: usize },
+    MisplacedSign { offset: usize },
+    MisplacedSeparator { offset: usize },
+}
+
+impl ParseBigIntError {
+    pub fn offset(&self) -> Option<usize> {
+        match *self {
+            Self::InvalidDigit { offset, .. }
+            | Self::MisplacedSign { offset }
+            | Self::MisplacedSeparator { offset } => Some(offset),
+            _ => None,
+        }
+    }
+}
+
+impl Display for ParseBigIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "cannot parse integer from empty string"),
+            Self::InvalidRadix(radix) => {
+                write!(f, "radix must be in 2..=36, got {radix}")
+            }
+            Self::InvalidDigit { digit, offset } => {
+                write!(f, "invalid digit {digit:?} at offset {offset}")
+            }
+            Self::MisplacedSign { offset } => {
+                write!(f, "misplaced sign at offset {offset}")
+            }
+            Self::MisplacedSeparator { offset } => {
+                write!(f, "misplaced separator at offset {offset}")
+            }
+        }
+    }
+}
+
+impl Error for ParseBigIntError {}
+
+impl BigInteger {
+    /// Parses an integer in the given radix with an optional leading sign.
+    /// A `0x`, `0o` or `0b` prefix is accepted if it matches the radix, and
+    /// single `_` separators are allowed between digits.
+    pub fn from_str_radix(
+        s: &str,
+        radix: u32,
+    ) -> Result<Self, ParseBigIntError> {
+        if !(2..=36).contains(&radix) {
+            return Err(ParseBigIntError::InvalidRadix(radix));
+        }
+        let (signum, offset) = match s.as_bytes().first() {
+            Some(b'-') => (-1, 1),
+            Some(b'+') => (1, 1),
+            _ => (1, 0),
+        };
+        let offset = match (radix, s[offset..].get(..2)) {
+            (16, Some("0x")) | (8, Some("0o")) | (2, Some("0b")) => offset + 2,
+            _ => offset,
+        };
+        parse_digits(s, offset, radix).map(|digits| {
+            Self::from_parts(from_radix_digits(&digits, radix), signum)
+        })
+    }
+}
+
+fn parse_digits(
+    s: &str,
+    offset: usize,
+    radix: u32,
+) -> Result<Vec<u8>, ParseBigIntError> {
+    let mut digits = Vec::with_capacity(s.len() - offset);
+    let mut separator = None;
+    for (i, c) in s[offset..].char_indices() {
+        let i = i + offset;
+        match c {
+            '_' if digits.is_empty() || separator.is_some() => {
+                return Err(ParseBigIntError::MisplacedSeparator { offset: i })
+            }
+            '_' => separator = Some(i),
+            '+' | '-' => {
+                return Err(ParseBigIntError::MisplacedSign { offset: i })
+            }
+            c => match c.to_digit(radix) {
+                Some(d) => {
+                    digits.push(d as u8);
+                    separator = None;
+                }
+                None => {
+                    return Err(ParseBigIntError::InvalidDigit {
+                        digit: c,
+                        offset: i,
+                    })
+                }
+            },
+        }
+    }
+    match (digits.is_empty(), separator) {
+        (true, _) => Err(ParseBigIntError::Empty),
+        (_, Some(offset)) => {
+            Err(ParseBigIntError::MisplacedSeparator { offset })
+        }
+        _ => Ok(digits),
+    }
+}
+
+/// Moves error offsets at or after `from` right by `by`, for errors found in
+/// a substring with characters removed.
+pub(crate) fn shift_offset(
+    e: ParseBigIntError,
+    from: usize,
+    by: usize,
+) -> ParseBigIntError {
+    let shift = |offset| if offset >= from { offset + by } else { offset };
+    match e {
+        ParseBigIntError::InvalidDigit { digit, offset } => {
+            ParseBigIntError::InvalidDigit { digit, offset: shift(offset) }
+        }
+        ParseBigIntError::MisplacedSign { offset } => {
+            ParseBigIntError::MisplacedSign { offset: shift(offset) }
+        }
+        ParseBigIntError::MisplacedSeparator { offset } => {
+            ParseBigIntError::MisplacedSeparator { offset: shift(offset) }
+        }
+        e => e,
+    }
+}
+
+/// Parses decimal digits around a point as one integer, returning it and the
+/// number of digits after the point. Error offsets refer to `int.frac`, and a
+/// separator next to the point is rejected.
+pub(crate) fn parse_fraction(
+    int: &str,
+    frac: &str,
+) -> Result<(BigInteger, usize), ParseBigIntError> {
+    if int.ends_with('_') {
+        return Err(ParseBigIntError::MisplacedSeparator {
+            offset: int.len() - 1,
+        });
+    }
+    if frac.starts_with('_') {
+        let offset = int.len() + 1;
+        return Err(ParseBigIntError::MisplacedSeparator { offset });
+    }
+    let x = BigInteger::from_str_radix(&format!("{int}{frac}"), 10)
+        .map_err(|e| shift_offset(e, int.len(), 1))?;
+    Ok((x, frac.bytes().filter(u8::is_ascii_digit).count()))
+}
+
+impl FromStr for BigInteger {
+    type Err = ParseBigIntError;
+
+    /// Parses a decimal integer, or a hexadecimal, octal or binary one if it
+    /// is prefixed with `0x`, `0o` or `0b` after the optional sign.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let unsigned = s.strip_prefix(['+', '-']).unwrap_or(s);
+        let radix = match unsigned.get(..2) {
+            Some("0x") => 16,
+            Some("0o") => 8,
+            Some("0b") => 2,
+            _ => 10,
+        };
+        Self::from_str_radix(s, radix)
+    }
+}
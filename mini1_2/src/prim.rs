@@ -0,0 +1,156 @@
+use super::*;
+
+macro_rules! impl_prim_ops {
+    ($t:ty, $split:expr) => {
+        impl AddAssign<$t> for BigInteger {
+            fn add_assign(&mut self, rhs: $t) {
+                let (x, signum): (u128, i8) = $split(rhs);
+                self.add_digits(&Limbs::from_u128(x), signum);
+            }
+        }
+
+        impl SubAssign<$t> for BigInteger {
+            fn sub_assign(&mut self, rhs: $t) {
+                let (x, signum): (u128, i8) = $split(rhs);
+                self.add_digits(&Limbs::from_u128(x), -signum);
+            }
+        }
+
+        impl MulAssign<$t> for BigInteger {
+            fn mul_assign(&mut self, rhs: $t) {
+                let (x, rhs_signum): (u128, i8) = $split(rhs);
+                let signum = self.signum * rhs_signum;
+                let product = self.to_u128().and_then(|y| y.checked_mul(x));
+                match (product, Digit::try_from(x)) {
+                    (Some(x), _) => *self = BigInteger::from_u128(x, signum),
+                    (None, Ok(digit)) => {
+                        let carry =
+                            mul_small_in_place(&mut self.digits, digit, 0);
+                        if carry != 0 {
+                            self.digits.push(carry);
+                        }
+                        self.digits.trim();
+                        self.signum = signum;
+                    }
+                    (None, Err(_)) => {
+                        *self *= &BigInteger::from_u128(x, rhs_signum)
+                    }
+                }
+            }
+        }
+
+        impl DivAssign<$t> for BigInteger {
+            fn div_assign(&mut self, rhs: $t) {
+                let (x, signum): (u128, i8) = $split(rhs);
+                assert!(signum != 0, "attempt to divide by zero");
+                match Digit::try_from(x) {
+                    Ok(digit) => {
+                        div_small(&mut self.digits, digit);
+                        self.digits.trim();
+                        self.signum *= signum * min(self.len(), 1) as i8;
+                    }
+                    Err(_) => *self /= &BigInteger::from_u128(x, signum),
+                }
+            }
+        }
+
+        impl RemAssign<$t> for BigInteger {
+            fn rem_assign(&mut self, rhs: $t) {
+                *self = &*self % rhs;
+            }
+        }
+
+        impl Rem<$t> for &BigInteger {
+            type Output = BigInteger;
+
+            fn rem(self, rhs: $t) -> Self::Output {
+                let (x, signum): (u128, i8) = $split(rhs);
+                assert!(
+                    signum != 0,
+                    "attempt to calculate the remainder with a divisor of zero"
+                );
+                match Digit::try_from(x) {
+                    Ok(digit) => BigInteger::from_u128(
+                        rem_small(&self.digits, digit) as u128,
+                        self.signum,
+                    ),
+                    Err(_) => self % &BigInteger::from_u128(x, signum),
+                }
+            }
+        }
+
+        impl Rem<$t> for BigInteger {
+            type Output = BigInteger;
+
+            fn rem(self, rhs: $t) -> Self::Output {
+                &self % rhs
+            }
+        }
+
+        impl_prim_ops!(@by_assign $t, Add, add, add_assign);
+        impl_prim_ops!(@by_assign $t, Sub, sub, sub_assign);
+        impl_prim_ops!(@by_assign $t, Mul, mul, mul_assign);
+        impl_prim_ops!(@by_assign $t, Div, div, div_assign);
+
+        impl_prim_ops!(@reverse $t, Add, add, |x, y| y + x);
+        impl_prim_ops!(@reverse $t, Sub, sub, |x, y| -(y - x));
+        impl_prim_ops!(@reverse $t, Mul, mul, |x, y| y * x);
+        impl_prim_ops!(@reverse $t, Div, div, |x, y| BigInteger::from(x) / y);
+        impl_prim_ops!(@reverse $t, Rem, rem, |x, y| BigInteger::from(x) % y);
+    };
+    (@by_assign $t:ty, $Op:ident, $op:ident, $assign:ident) => {
+        impl $Op<$t> for BigInteger {
+            type Output = BigInteger;
+
+            fn $op(mut self, rhs: $t) -> Self::Output {
+                self.$assign(rhs);
+                self
+            }
+        }
+
+        impl $Op<$t> for &BigInteger {
+            type Output = BigInteger;
+
+            fn $op(self, rhs: $t) -> Self::Output {
+                self.clone().$op(rhs)
+            }
+        }
+    };
+    (@reverse $t:ty, $Op:ident, $op:ident, |$x:ident, $y:ident| $e:expr) => {
+        impl $Op<BigInteger> for $t {
+            type Output = BigInteger;
+
+            fn $op(self, rhs: BigInteger) -> Self::Output {
+                let ($x, $y) = (self, rhs);
+                $e
+            }
+        }
+
+        impl $Op<&BigInteger> for $t {
+            type Output = BigInteger;
+
+            fn $op(self, rhs: &BigInteger) -> Self::Output {
+                let ($x, $y) = (self, rhs);
+                $e
+            }
+        }
+    };
+}
+
+macro_rules! impl_unsigned_ops {
+    ($($t:ty),*) => {
+        $(impl_prim_ops!($t, |x: $t| (x as u128, (x != 0) as i8));)*
+    };
+}
+
+macro_rules! impl_signed_ops {
+    ($($t:ty),*) => {
+        $(impl_prim_ops!(
+            $t,
+            |x: $t| (x.unsigned_abs() as u128, x.signum() as i8)
+        );)*
+    };
+}
+
+impl_unsigned_ops!(u8, u16, u32, u64, u128, usize);
+impl_signed_ops!(i8, i16, i32, i64, i128, isize);
@@ -0,0 +1,218 @@
+use std::sync::OnceLock;
+
+use super::*;
+
+const TRIAL_DIVISION_LIMIT: u64 = 1000;
+const PRIME_ROUNDS: u32 = 20;
+const POLLARD_PM1_BOUND: u64 = 10000;
+
+const DETERMINISTIC_BASES: [u64; 12] =
+    [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+pub(crate) fn small_primes(limit: u64) -> Vec<u64> {
+    let mut sieve = vec![true; limit as usize + 1];
+    let mut primes = Vec::new();
+    for i in 2..=limit as usize {
+        if sieve[i] {
+            primes.push(i as u64);
+            for j in (i * i..=limit as usize).step_by(i) {
+                sieve[j] = false;
+            }
+        }
+    }
+    primes
+}
+
+/// Primes below `TRIAL_DIVISION_LIMIT`, sieved once on first use.
+fn trial_primes() -> &'static [u64] {
+    static PRIMES: OnceLock<Vec<u64>> = OnceLock::new();
+    PRIMES.get_or_init(|| small_primes(TRIAL_DIVISION_LIMIT))
+}
+
+impl BigInteger {
+    fn is_one(&self) -> bool {
+        self.signum == 1 && self.digits[..] == [1]
+    }
+
+    fn miller_rabin(&self, d: &Self, s: u64, base: &Self) -> bool {
+        let n_minus_one = self - 1_u8;
+        let mut x = base.mod_pow(d, self);
+        if x.is_one() || x == n_minus_one {
+            return true;
+        }
+        for _ in 1..s {
+            x = &x * &x % self;
+            if x == n_minus_one {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Miller-Rabin test. Values below 2^64 are checked against a
+    /// deterministic set of bases and the answer is exact; larger values
+    /// are additionally checked against `rounds` pseudo-random bases.
+    pub fn is_probable_prime(&self, rounds: u32) -> bool {
+        if *self < Self::from(2_u8) {
+            return false;
+        }
+        for &p in trial_primes() {
+            if self.len() == 1 && self.digits[0] == p {
+                return true;
+            }
+            if self % p == Self::ZERO {
+                return false;
+            }
+        }
+        if self.len() == 1 && self.digits[0] < TRIAL_DIVISION_LIMIT.pow(2) {
+            return true;
+        }
+
+        let n_minus_one = self - 1_u8;
+        let s = n_minus_one.trailing_zeros().unwrap();
+        let d = &n_minus_one >> s as usize;
+        let deterministic = DETERMINISTIC_BASES.iter().map(|&b| Self::from(b));
+        if !deterministic.into_iter().all(|b| self.miller_rabin(&d, s, &b)) {
+            return false;
+        }
+        if self.len() == 1 {
+            return true;
+        }
+
+        let mut seed = self.digits[0] | 1;
+        let range = self - 3_u8;
+        (0..rounds).all(|_| {
+            let digits = (0..self.len())
+                .map(|_| {
+                    seed ^= seed << 13;
+                    seed ^= seed >> 7;
+                    seed ^= seed << 17;
+                    seed
+                })
+                .collect();
+            let base = Self::from_parts(digits, 1) % &range + 2_u8;
+            self.miller_rabin(&d, s, &base)
+        })
+    }
+
+    /// Smallest prime greater than `self`.
+    pub fn next_prime(&self) -> Self {
+        if *self < Self::from(2_u8) {
+            return Self::from(2_u8);
+        }
+        let mut candidate = self + 1_u8;
+        candidate.set_bit(0, true);
+        while !candidate.is_probable_prime(PRIME_ROUNDS) {
+            candidate += 2_u8;
+        }
+        candidate
+    }
+
+    /// Largest prime less than `self`, or `None` if there is none.
+    pub fn prev_prime(&self) -> Option<Self> {
+        if *self <= Self::from(2_u8) {
+            return None;
+        }
+        if *self == Self::from(3_u8) {
+            return Some(Self::from(2_u8));
+        }
+        let mut candidate = self - 1_u8;
+        if !candidate.bit(0) {
+            candidate -= 1_u8;
+        }
+        while !candidate.is_probable_prime(PRIME_ROUNDS) {
+            candidate -= 2_u8;
+        }
+        Some(candidate)
+    }
+
+    /// Prime factors of `|self|` in ascending order, with multiplicity.
+    /// Small factors are found by trial division, the rest by Pollard's
+    /// p-1 and Brent's variant of Pollard's rho.
+    pub fn factor(&self) -> Vec<Self> {
+        let mut n = self.clone().abs();
+        let mut factors = Vec::new();
+        if n <= Self::from(1_u8) {
+            return factors;
+        }
+        for &p in trial_primes() {
+            while &n % p == Self::ZERO {
+                n /= p;
+                factors.push(Self::from(p));
+            }
+        }
+
+        let mut stack = vec![n];
+        while let Some(n) = stack.pop() {
+            if n.is_one() {
+                continue;
+            }
+            if n.is_probable_prime(PRIME_ROUNDS) {
+                factors.push(n);
+                continue;
+            }
+            let d = n
+                .pollard_pm1(POLLARD_PM1_BOUND)
+                .or_else(|| (1_u64..).find_map(|c| n.pollard_brent(c)))
+                .unwrap();
+            stack.push(&n / &d);
+            stack.push(d);
+        }
+        factors.sort();
+        factors
+    }
+
+    fn pollard_pm1(&self, bound: u64) -> Option<Self> {
+        let mut a = Self::from(2_u8);
+        for p in small_primes(bound) {
+            let mut q = p;
+            while q * p <= bound {
+                q *= p;
+            }
+            a = a.mod_pow(&Self::from(q), self);
+        }
+        let g = (a - 1_u8).gcd(self);
+        match g.is_one() || g == *self {
+            true => None,
+            false => Some(g),
+        }
+    }
+
+    fn pollard_brent(&self, c: u64) -> Option<Self> {
+        const M: u64 = 128;
+        let f = |x: &Self| (x * x + c) % self;
+        let (mut y, mut r) = (Self::from(2_u8), 1);
+        let (mut q, mut g) = (Self::from(1_u8), Self::from(1_u8));
+        let (mut x, mut ys) = (y.clone(), y.clone());
+        while g.is_one() {
+            x = y.clone();
+            for _ in 0..r {
+                y = f(&y);
+            }
+            let mut k = 0;
+            while k < r && g.is_one() {
+                ys = y.clone();
+                for _ in 0..min(M, r - k) {
+                    y = f(&y);
+                    q = q * (&x - &y).abs() % self;
+                }
+                g = q.gcd(self);
+                k += M;
+            }
+            r *= 2;
+        }
+        if g == *self {
+            loop {
+                ys = f(&ys);
+                g = (&x - &ys).abs().gcd(self);
+                if !g.is_one() {
+                    break;
+                }
+            }
+        }
+        match g == *self {
+            true => None,
+            false => Some(g),
+        }
+    }
+}
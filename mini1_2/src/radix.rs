@@ -0,0 +1,92 @@
+use super::*;
+
+/// Limb count below which radix conversion falls back to repeated
+/// single-limb multiplication or division.
+pub const RADIX_CONVERSION_THRESHOLD: usize = 64;
+
+/// Returns `radix^(n * 2^i)` for each `i` while the power has at most
+/// `limbs / 2` limbs, where `radix^n` is the largest power fitting a limb.
+fn radix_powers(radix: u32, limbs: usize) -> Vec<BigInteger> {
+    let (base, _) = radix_chunk(radix);
+    let mut powers = vec![BigInteger::from(base)];
+    while powers.last().unwrap().len() * 2 <= limbs {
+        let last = powers.last().unwrap();
+        powers.push(last * last);
+    }
+    powers
+}
+
+/// Little-endian digits of `|x|`, computed by divide and conquer: the value
+/// is split by the largest precomputed power of the base and both halves
+/// are converted recursively.
+pub(crate) fn to_radix_digits(x: &BigInteger, radix: u32) -> Vec<u8> {
+    if x.len() <= RADIX_CONVERSION_THRESHOLD {
+        return to_radix(x.digits.to_vec(), radix);
+    }
+    let powers = radix_powers(radix, x.len());
+    let mut out = Vec::new();
+    to_radix_rec(x.clone().abs(), &powers, radix, 0, &mut out);
+    trunc(out)
+}
+
+/// Appends `width` digits of `x`, or all of them if `width` is zero.
+fn to_radix_rec(
+    x: BigInteger,
+    powers: &[BigInteger],
+    radix: u32,
+    width: usize,
+    out: &mut Vec<u8>,
+) {
+    let start = out.len();
+    match powers.split_last() {
+        Some((power, rest)) if x.len() > RADIX_CONVERSION_THRESHOLD => {
+            if x < *power {
+                to_radix_rec(x, rest, radix, width, out);
+            } else {
+                let (q, r) = x.div_rem(power);
+                let low = radix_chunk(radix).1 << rest.len();
+                to_radix_rec(r, rest, radix, low, out);
+                let high = width.saturating_sub(low);
+                to_radix_rec(q, rest, radix, high, out);
+            }
+        }
+        _ => out.extend(to_radix(x.digits.into_vec(), radix)),
+    }
+    if width > 0 {
+        out.resize(start + width, 0);
+    }
+}
+
+/// Magnitude from big-endian digit values, by splitting the digits, converting
+/// both halves recursively and combining them with a precomputed power.
+pub(crate) fn from_radix_digits(digits: &[u8], radix: u32) -> Vec<Digit> {
+    let (_, n) = radix_chunk(radix);
+    let limbs = digits.len() / n;
+    if limbs <= RADIX_CONVERSION_THRESHOLD {
+        return from_radix(digits, radix);
+    }
+    let powers = radix_powers(radix, limbs);
+    from_radix_rec(digits, &powers, radix).digits.into_vec()
+}
+
+fn from_radix_rec(
+    digits: &[u8],
+    powers: &[BigInteger],
+    radix: u32,
+) -> BigInteger {
+    let (_, n) = radix_chunk(radix);
+    match powers.split_last() {
+        Some((power, rest))
+            if digits.len() / n > RADIX_CONVERSION_THRESHOLD =>
+        {
+            let m = n << rest.len();
+            if m >= digits.len() {
+                return from_radix_rec(digits, rest, radix);
+            }
+            let (high, low) = digits.split_at(digits.len() - m);
+            from_radix_rec(high, rest, radix) * power
+                + from_radix_rec(low, rest, radix)
+        }
+        _ => BigInteger::from_parts(from_radix(digits, radix), 1),
+    }
+}
@@ -0,0 +1,47 @@
+use rand::RngCore;
+
+use super::*;
+
+impl BigInteger {
+    /// A uniformly random value in `0..2^bits`.
+    pub fn random_bits<R: RngCore + ?Sized>(bits: u64, rng: &mut R) -> Self {
+        let n = bits.div_ceil(DIGIT_BITS as u64) as usize;
+        let mut digits: Vec<Digit> = (0..n).map(|_| rng.next_u64()).collect();
+        if let Some(d) = digits.last_mut() {
+            *d &= Digit::MAX >> (n as u64 * DIGIT_BITS as u64 - bits);
+        }
+        Self::from_parts(digits, 1)
+    }
+
+    /// A uniformly random value in `0..bound`, by rejection sampling.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bound` is not positive.
+    pub fn random_below<R: RngCore + ?Sized>(
+        bound: &Self,
+        rng: &mut R,
+    ) -> Self {
+        assert!(bound.signum() > 0, "bound must be positive");
+        loop {
+            let x = Self::random_bits(bound.bit_length(), rng);
+            if x < *bound {
+                return x;
+            }
+        }
+    }
+
+    /// A uniformly random value in `lo..hi`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lo >= hi`.
+    pub fn random_range<R: RngCore + ?Sized>(
+        lo: &Self,
+        hi: &Self,
+        rng: &mut R,
+    ) -> Self {
+        assert!(lo < hi, "empty range");
+        lo + Self::random_below(&(hi - lo), rng)
+    }
+}
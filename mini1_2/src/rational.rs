@@ -0,0 +1,280 @@
+use std::error::Error;
+use std::fmt::{self, Debug, Display};
+use std::str::FromStr;
+
+use super::*;
+
+/// An exact rational number, kept in lowest terms with a positive
+/// denominator.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct BigRational {
+    num: BigInteger,
+    den: BigInteger,
+}
+
+impl BigRational {
+    /// # Panics
+    ///
+    /// Panics if `den` is zero.
+    pub fn new(num: BigInteger, den: BigInteger) -> Self {
+        assert!(den.signum() != 0, "denominator must be nonzero");
+        let g = num.gcd(&den);
+        let (num, den) = (num / &g, den / &g);
+        match den.signum() {
+            -1 => BigRational { num: -num, den: -den },
+            _ => BigRational { num, den },
+        }
+    }
+
+    pub fn num(&self) -> &BigInteger {
+        &self.num
+    }
+
+    pub fn den(&self) -> &BigInteger {
+        &self.den
+    }
+
+    pub fn signum(&self) -> i8 {
+        self.num.signum()
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.den == BigInteger::from(1_u8)
+    }
+
+    pub fn abs(self) -> Self {
+        BigRational { num: self.num.abs(), den: self.den }
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
+    pub fn recip(&self) -> Self {
+        Self::new(self.den.clone(), self.num.clone())
+    }
+
+    pub fn floor(&self) -> BigInteger {
+        self.num.div_floor(&self.den)
+    }
+
+    pub fn ceil(&self) -> BigInteger {
+        -(-self.num.clone()).div_floor(&self.den)
+    }
+
+    pub fn trunc(&self) -> BigInteger {
+        &self.num / &self.den
+    }
+
+    /// Rounds to the nearest integer, with ties away from zero.
+    pub fn round(&self) -> BigInteger {
+        let num = (self.num.clone().abs() << 1) + &self.den;
+        let r = num / (&self.den << 1);
+        match self.signum() {
+            -1 => -r,
+            _ => r,
+        }
+    }
+
+    /// Converts to the nearest `f64`, with ties to even outside the subnormal
+    /// range.
+    pub fn to_f64(&self) -> f64 {
+        if self.signum() == 0 {
+            return 0.0;
+        }
+        let num = self.num.clone().abs();
+        let shift = 65 + self.den.bit_length() as i64 - num.bit_length() as i64;
+        let (mut q, r) = match shift {
+            0.. => (num << shift as usize).div_rem(&self.den),
+            _ => num.div_rem(&(&self.den << -shift as usize)),
+        };
+        if r.signum() != 0 {
+            q.set_bit(0, true);
+        }
+        let mut x = q.to_f64() * self.signum() as f64;
+        let mut e = -shift.clamp(-2200, 2200);
+        while e != 0 {
+            let step = e.clamp(-1000, 1000);
+            x *= 2_f64.powi(step as i32);
+            e -= step;
+        }
+        x
+    }
+}
+
+impl From<BigInteger> for BigRational {
+    fn from(num: BigInteger) -> Self {
+        BigRational { num, den: BigInteger::from(1_u8) }
+    }
+}
+
+impl Default for BigRational {
+    fn default() -> Self {
+        BigInteger::ZERO.into()
+    }
+}
+
+impl PartialOrd for BigRational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigRational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&self.num * &other.den).cmp(&(&other.num * &self.den))
+    }
+}
+
+impl Neg for BigRational {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        BigRational { num: -self.num, den: self.den }
+    }
+}
+
+impl Neg for &BigRational {
+    type Output = BigRational;
+
+    fn neg(self) -> Self::Output {
+        -self.clone()
+    }
+}
+
+impl Add<&BigRational> for &BigRational {
+    type Output = BigRational;
+
+    fn add(self, rhs: &BigRational) -> Self::Output {
+        let num = &self.num * &rhs.den + &rhs.num * &self.den;
+        BigRational::new(num, &self.den * &rhs.den)
+    }
+}
+
+impl Sub<&BigRational> for &BigRational {
+    type Output = BigRational;
+
+    fn sub(self, rhs: &BigRational) -> Self::Output {
+        let num = &self.num * &rhs.den - &rhs.num * &self.den;
+        BigRational::new(num, &self.den * &rhs.den)
+    }
+}
+
+impl Mul<&BigRational> for &BigRational {
+    type Output = BigRational;
+
+    fn mul(self, rhs: &BigRational) -> Self::Output {
+        BigRational::new(&self.num * &rhs.num, &self.den * &rhs.den)
+    }
+}
+
+impl Div<&BigRational> for &BigRational {
+    type Output = BigRational;
+
+    fn div(self, rhs: &BigRational) -> Self::Output {
+        assert!(rhs.signum() != 0, "attempt to divide by zero");
+        BigRational::new(&self.num * &rhs.den, &self.den * &rhs.num)
+    }
+}
+
+macro_rules! rational_assign {
+    ($($Assign:ident, $assign:ident, $op:ident;)*) => {$(
+        impl $Assign<&BigRational> for BigRational {
+            fn $assign(&mut self, rhs: &BigRational) {
+                *self = (&*self).$op(rhs);
+            }
+        }
+    )*};
+}
+
+rational_assign! {
+    AddAssign, add_assign, add;
+    SubAssign, sub_assign, sub;
+    MulAssign, mul_assign, mul;
+    DivAssign, div_assign, div;
+}
+
+forward_binop_to_ref!(BigRational, Add, add);
+forward_binop_to_ref!(BigRational, Sub, sub);
+forward_binop_to_ref!(BigRational, Mul, mul);
+forward_binop_to_ref!(BigRational, Div, div);
+forward_assign!(BigRational, AddAssign, add_assign);
+forward_assign!(BigRational, SubAssign, sub_assign);
+forward_assign!(BigRational, MulAssign, mul_assign);
+forward_assign!(BigRational, DivAssign, div_assign);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseRationalError {
+    Integer(ParseBigIntError),
+    ZeroDenominator,
+}
+
+impl Display for ParseRationalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Integer(e) => Display::fmt(e, f),
+            Self::ZeroDenominator => write!(f, "denominator is zero"),
+        }
+    }
+}
+
+impl Error for ParseRationalError {}
+
+impl FromStr for BigRational {
+    type Err = ParseRationalError;
+
+    /// Parses a decimal fraction `a/b` or a decimal number such as `-1.25`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse = |s: &str, from: usize, by: usize| {
+            BigInteger::from_str_radix(s, 10).map_err(|e| {
+                ParseRationalError::Integer(shift_offset(e, from, by))
+            })
+        };
+        let (num, den) = if let Some((a, b)) = s.split_once('/') {
+            (parse(a, 0, 0)?, parse(b, 0, a.len() + 1)?)
+        } else if let Some((a, b)) = s.split_once('.') {
+            let (num, digits) =
+                parse_fraction(a, b).map_err(ParseRationalError::Integer)?;
+            (num, BigInteger::from(10_u8).pow(digits as u32))
+        } else {
+            (parse(s, 0, 0)?, BigInteger::from(1_u8))
+        };
+        if den.signum() == 0 {
+            return Err(ParseRationalError::ZeroDenominator);
+        }
+        Ok(Self::new(num, den))
+    }
+}
+
+impl Display for BigRational {
+    /// Prints `a/b`, or just `a` for integers. With a precision, prints the
+    /// value rounded to that many decimal places, with ties away from zero.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (nonneg, s) = match f.precision() {
+            Some(p) => {
+                let scale = BigInteger::from(10_u8).pow(p as u32);
+                let r = (self * &BigRational::from(scale)).round();
+                let digits =
+                    format!("{:0>1$}", r.clone().abs().to_string(), p + 1);
+                let (int, frac) = digits.split_at(digits.len() - p);
+                match p {
+                    0 => (r.signum() >= 0, int.to_string()),
+                    _ => (r.signum() >= 0, format!("{int}.{frac}")),
+                }
+            }
+            None if self.is_integer() => {
+                (self.signum() >= 0, self.num.clone().abs().to_string())
+            }
+            None => (
+                self.signum() >= 0,
+                format!("{}/{}", self.num.clone().abs(), self.den),
+            ),
+        };
+        f.pad_integral(nonneg, "", &s)
+    }
+}
+
+impl Debug for BigRational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
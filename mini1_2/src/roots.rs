@@ -0,0 +1,128 @@
+use super::*;
+
+impl BigInteger {
+    pub fn pow(&self, mut exp: u32) -> Self {
+        let mut base = self.clone();
+        let mut result = Self::from(1_u8);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= &base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = &base * &base;
+            }
+        }
+        result
+    }
+
+    /// Integer square root, rounded down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is negative.
+    pub fn isqrt(&self) -> Self {
+        self.nth_root(2)
+    }
+
+    /// Integer `n`-th root, rounded toward zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero, or if `n` is even and `self` is negative.
+    pub fn nth_root(&self, n: u32) -> Self {
+        assert!(n > 0, "root degree must be positive");
+        match self.signum() {
+            0 => return Self::ZERO,
+            -1 if n.is_multiple_of(2) => {
+                panic!("even root of a negative number")
+            }
+            -1 => return -(-self.clone()).nth_root(n),
+            _ => {}
+        }
+        if n == 1 {
+            return self.clone();
+        }
+
+        let bits = self.bit_length().div_ceil(n as u64) as usize;
+        let mut x = Self::from(1_u8) << bits;
+        loop {
+            let y = (&x * (n - 1) + self / &x.pow(n - 1)) / n;
+            if y >= x {
+                return x;
+            }
+            x = y;
+        }
+    }
+
+    pub fn is_perfect_square(&self) -> bool {
+        match self.signum() {
+            -1 => false,
+            0 => true,
+            _ if (0x202021202030213_u64 >> (self.digits[0] & 63)) & 1 == 0 => {
+                false
+            }
+            _ => {
+                let root = self.isqrt();
+                &root * &root == *self
+            }
+        }
+    }
+
+    fn log2_approx(&self) -> f64 {
+        let bits = self.bit_length();
+        let shift = bits.saturating_sub(DIGIT_BITS as u64) as usize;
+        (self >> shift).to_f64().log2() + shift as f64
+    }
+
+    /// Floor of the base 2 logarithm.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not positive.
+    pub fn ilog2(&self) -> u64 {
+        assert!(
+            self.signum() > 0,
+            "argument of integer logarithm must be positive"
+        );
+        self.bit_length() - 1
+    }
+
+    /// Floor of the base 10 logarithm.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not positive.
+    pub fn ilog10(&self) -> u64 {
+        self.ilog(&Self::from(10_u8))
+    }
+
+    /// Floor of the logarithm in the given base.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not positive or `base` is less than 2.
+    pub fn ilog(&self, base: &Self) -> u64 {
+        assert!(
+            self.signum() > 0,
+            "argument of integer logarithm must be positive"
+        );
+        assert!(
+            *base > Self::from(1_u8),
+            "base of integer logarithm must be at least 2"
+        );
+        let mut k = (self.log2_approx() / base.log2_approx()) as u32;
+        let mut power = base.pow(k);
+        while power > *self {
+            k -= 1;
+            power /= base;
+        }
+        loop {
+            power *= base;
+            if power > *self {
+                return k as u64;
+            }
+            k += 1;
+        }
+    }
+}